@@ -1,13 +1,131 @@
 use colored::*;
+use indicatif::{ProgressBar, ProgressStyle};
 use memory_stats::memory_stats;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
 use std::time::{Duration, Instant};
 
-use crate::geometry::Point;
-use crate::matrix::Matrix;
+use crate::geometry::{self, ClosestPairResult, KdTree, Point};
+use crate::logging::{self, detail, error, status, LogLevel};
+use crate::matrix::{Matrix, MultiplyAlgorithm};
 use crate::sorting;
 
+type ClosestPairFn = fn(&[Point]) -> Option<ClosestPairResult>;
+type SortFn = fn(&mut [i32]);
+
+/// A sort known to `benchmark_sort`, with its Rayon variant if it has one
+pub struct SortAlgorithm {
+    pub name: &'static str,
+    pub sequential: SortFn,
+    pub parallel: Option<SortFn>,
+    /// Leading-term working memory in bytes for `n` elements, used by `estimated_memory`
+    pub memory: fn(usize) -> usize,
+}
+
+impl SortAlgorithm {
+    /// The routine for the requested mode, if this sort supports it
+    pub fn function(&self, parallel: bool) -> Option<SortFn> {
+        if parallel {
+            self.parallel
+        } else {
+            Some(self.sequential)
+        }
+    }
+}
+
+/// Every sort the sort benchmark runs, in run order
+/// "Std Sort" and "Std Stable" are the standard library (or Rayon) baselines
+pub const SORT_ALGORITHMS: [SortAlgorithm; 5] = [
+    SortAlgorithm {
+        name: "Merge Sort",
+        sequential: sorting::merge_sort,
+        parallel: Some(sorting::parallel_merge_sort),
+        memory: linear_sort_memory,
+    },
+    // Sequential only; it is here to compare memory use with "Merge Sort"
+    SortAlgorithm {
+        name: "Single-Buffer Merge Sort",
+        sequential: sorting::merge_sort_in_place,
+        parallel: None,
+        memory: linear_sort_memory,
+    },
+    SortAlgorithm {
+        name: "Quick Sort",
+        sequential: sorting::quick_sort,
+        parallel: Some(sorting::parallel_quick_sort),
+        memory: logarithmic_sort_memory,
+    },
+    SortAlgorithm {
+        name: "Std Sort",
+        sequential: |arr| arr.sort_unstable(),
+        parallel: Some(|arr| arr.par_sort_unstable()),
+        memory: logarithmic_sort_memory,
+    },
+    SortAlgorithm {
+        name: "Std Stable",
+        sequential: |arr| arr.sort(),
+        parallel: Some(|arr| arr.par_sort()),
+        // std's stable sort allocates an n/2 buffer, still O(n)
+        memory: linear_sort_memory,
+    },
+];
+
+/// Merge buffer of one element per input element
+fn linear_sort_memory(n: usize) -> usize {
+    n * std::mem::size_of::<i32>()
+}
+
+/// Recursion stack only
+fn logarithmic_sort_memory(n: usize) -> usize {
+    (n.max(1).ilog2() as usize + 1) * std::mem::size_of::<i32>()
+}
+
+/// Problem domain of a benchmarked algorithm
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub enum Category {
+    Sorting,
+    Matrix,
+    Geometry,
+    /// Custom closures and results saved before categories existed
+    #[default]
+    Other,
+}
+
+impl Category {
+    pub const ALL: [Category; 4] = [
+        Category::Sorting,
+        Category::Matrix,
+        Category::Geometry,
+        Category::Other,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Category::Sorting => "Sorting",
+            Category::Matrix => "Matrix",
+            Category::Geometry => "Geometry",
+            Category::Other => "Other",
+        }
+    }
+}
+
+impl std::str::FromStr for Category {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Category::ALL
+            .into_iter()
+            .find(|category| category.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown category '{}'", s))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BenchmarkResult {
     pub algorithm_name: String,
@@ -15,16 +133,319 @@ pub struct BenchmarkResult {
     pub execution_time: Duration,
     pub memory_used: Option<usize>,
     pub parallel: bool,
+    /// Individual run durations; older result files may not contain these
+    #[serde(default)]
+    pub run_times: Vec<Duration>,
+    /// Set when a run exceeded the runner's timeout and was abandoned
+    #[serde(default)]
+    pub timed_out: bool,
+    /// Size of the algorithm's output where meaningful (e.g. hull vertices)
+    #[serde(default)]
+    pub output_count: Option<usize>,
+    #[serde(default)]
+    pub category: Category,
+}
+
+/// Asymptotic growth of an algorithm's running time, used to extrapolate timings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Complexity {
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Cubic,
+    /// n^exponent, e.g. log2(7) for Strassen
+    Power(f64),
+}
+
+impl Complexity {
+    /// Relative cost of an input of size `n`
+    pub fn cost(&self, n: usize) -> f64 {
+        let n = n.max(1) as f64;
+        match self {
+            Complexity::Linear => n,
+            Complexity::Linearithmic => n * n.log2().max(1.0),
+            Complexity::Quadratic => n * n,
+            Complexity::Cubic => n * n * n,
+            Complexity::Power(exponent) => n.powf(*exponent),
+        }
+    }
+}
+
+/// Predict the time at `target_size` from one run measured at `calibration_size`
+pub fn estimate_runtime(
+    calibration_time: Duration,
+    calibration_size: usize,
+    target_size: usize,
+    complexity: Complexity,
+) -> Duration {
+    let ratio = complexity.cost(target_size) / complexity.cost(calibration_size);
+    calibration_time.mul_f64(ratio)
+}
+
+/// Fitted exponent k of time ≈ c·n^k, from a least-squares line through (ln n, ln t)
+/// Points with a zero size or non-positive time are ignored; returns NaN unless
+/// at least two distinct sizes remain
+pub fn analyze_complexity(points: &[(usize, f64)]) -> f64 {
+    let logs: Vec<(f64, f64)> = points
+        .iter()
+        .filter(|&&(size, time)| size > 0 && time > 0.0)
+        .map(|&(size, time)| ((size as f64).ln(), time.ln()))
+        .collect();
+    if logs.is_empty() {
+        return f64::NAN;
+    }
+
+    let count = logs.len() as f64;
+    let mean_x = logs.iter().map(|&(x, _)| x).sum::<f64>() / count;
+    let mean_y = logs.iter().map(|&(_, y)| y).sum::<f64>() / count;
+    let (covariance, variance) = logs.iter().fold((0.0, 0.0), |(cov, var), &(x, y)| {
+        (
+            cov + (x - mean_x) * (y - mean_y),
+            var + (x - mean_x) * (x - mean_x),
+        )
+    });
+
+    if variance == 0.0 {
+        f64::NAN
+    } else {
+        covariance / variance
+    }
+}
+
+/// Analytic estimate of a result's working memory in bytes, from the algorithm's
+/// space complexity times the element size; unlike the RSS sampler it is not
+/// affected by the allocator or other threads
+///
+/// Only the leading term is modeled, so this is for comparing orders of magnitude.
+/// Sorts use the `memory` column of `SORT_ALGORITHMS` and matrix results the
+/// multiply method in their name; returns `None` for algorithms with no model,
+/// including custom closures
+pub fn estimated_memory(result: &BenchmarkResult) -> Option<usize> {
+    let n = result.data_size;
+    let log_n = n.max(1).ilog2() as usize + 1;
+    let name = result
+        .algorithm_name
+        .strip_suffix(" (Parallel)")
+        .unwrap_or(&result.algorithm_name);
+    let float = std::mem::size_of::<f64>();
+    let point = std::mem::size_of::<Point>();
+
+    match result.category {
+        Category::Sorting => SORT_ALGORITHMS
+            .iter()
+            .find(|sort| sort.name == name)
+            .map(|sort| (sort.memory)(n)),
+        Category::Matrix => {
+            let method = MultiplyAlgorithm::ALL
+                .into_iter()
+                .find(|method| name.ends_with(&format!("({})", method.name())))?;
+            let cells = n * n * float;
+            Some(match method {
+                MultiplyAlgorithm::Standard => cells,
+                // Quadrant copies plus the seven products at each level
+                MultiplyAlgorithm::Strassen => 3 * cells,
+                MultiplyAlgorithm::Winograd => cells + 2 * n * float,
+            })
+        }
+        Category::Geometry => match name {
+            // x-sorted copy plus the merge scratch buffer
+            "Closest Pair" | "Closest Pair (Divide & Conquer)" => Some(2 * n * point),
+            "Closest Pair (Brute Force)" => Some(0),
+            "Convex Hull" => Some(n * point),
+            // One node per point: the point, two child pointers and the split axis
+            "KdTree Build" => Some(n * (point + 3 * std::mem::size_of::<usize>())),
+            // Explicit search stack of (node, plane distance) entries
+            "KdTree Query" => Some(log_n * (std::mem::size_of::<usize>() + float)),
+            _ => None,
+        },
+        Category::Other => None,
+    }
+}
+
+/// Current layout of saved result files
+///
+/// Version 1 is the bare JSON array written before files were versioned;
+/// fields added since then are filled in by `#[serde(default)]` when loading it
+pub const RESULTS_FILE_VERSION: u32 = 2;
+
+/// On-disk wrapper for saved benchmark results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkFile {
+    pub version: u32,
+    pub results: Vec<BenchmarkResult>,
+}
+
+/// Parallel speedup of one algorithm at one data size
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeedupEntry {
+    pub algorithm: String,
+    pub data_size: usize,
+    pub sequential_time: Duration,
+    pub parallel_time: Duration,
+    /// Sequential time divided by parallel time; above 1.0 means parallel is faster
+    pub speedup: f64,
+}
+
+/// Outcome of `BenchmarkRunner::benchmark_compare`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonOutcome {
+    pub winner: String,
+    pub loser: String,
+    /// Loser's average time divided by the winner's; always at least 1.0
+    pub factor: f64,
 }
 
 pub struct BenchmarkRunner {
     results: Vec<BenchmarkResult>,
+    show_progress: bool,
+    /// One bar counting runs across every benchmark method; created on first use
+    progress: Option<ProgressBar>,
+    /// Whether the bar's total was fixed up front by `set_planned_runs`
+    runs_planned: bool,
+    timeout: Option<Duration>,
+    /// Accumulated time per folded stack, e.g. "Merge Sort;merge"
+    phase_timings: Vec<(String, Duration)>,
+    /// Set (e.g. from a Ctrl-C handler) to stop benchmarking between runs
+    cancel_flag: Arc<AtomicBool>,
+}
+
+impl Default for BenchmarkRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for BenchmarkRunner {
+    fn drop(&mut self) {
+        self.finish_progress();
+    }
 }
 
 impl BenchmarkRunner {
     pub fn new() -> Self {
         Self {
             results: Vec::new(),
+            show_progress: true,
+            progress: None,
+            runs_planned: false,
+            timeout: None,
+            phase_timings: Vec::new(),
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Share `flag` with the runner; once it is set, benchmarks stop before their next run
+    /// Results recorded so far are kept, and a partially run benchmark records its completed runs
+    pub fn set_cancel_flag(&mut self, flag: Arc<AtomicBool>) {
+        self.cancel_flag = flag;
+    }
+
+    /// Whether the cancel flag has been set
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_flag.load(Ordering::Relaxed)
+    }
+
+    /// Abandon any single run that takes longer than `timeout`
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Time `work`, running it on a separate thread when a timeout is set
+    /// Returns `None` if the run timed out; the abandoned thread is left to finish on its own
+    fn run_timed<T, F>(&self, work: F) -> Option<(Duration, T)>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let Some(timeout) = self.timeout else {
+            let start = Instant::now();
+            let output = work();
+            return Some((start.elapsed(), output));
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let start = Instant::now();
+            let output = work();
+            let _ = sender.send((start.elapsed(), output));
+        });
+
+        receiver.recv_timeout(timeout).ok()
+    }
+
+    fn print_timeout(&self) {
+        error!(
+            "    {}",
+            format!(
+                "Timed out after {:.2}ms",
+                self.timeout.unwrap_or_default().as_secs_f64() * 1000.0
+            )
+            .red()
+        );
+    }
+
+    /// Enable or disable the per-run progress bar
+    pub fn set_show_progress(&mut self, show_progress: bool) {
+        self.show_progress = show_progress;
+    }
+
+    /// Fix the progress bar's total to `total` runs across all upcoming benchmarks,
+    /// so it shows overall progress and ETA; without a plan each benchmark method
+    /// adds its own runs and the bar is cleared whenever it catches up
+    pub fn set_planned_runs(&mut self, total: usize) {
+        self.progress_bar().set_length(total as u64);
+        self.runs_planned = true;
+    }
+
+    /// Clear the progress bar, e.g. before printing results after a cancelled run
+    pub fn finish_progress(&mut self) {
+        if let Some(bar) = self.progress.take() {
+            bar.finish_and_clear();
+            if !bar.is_hidden() {
+                logging::set_progress_bar(None);
+            }
+        }
+        self.runs_planned = false;
+    }
+
+    /// The shared progress bar (hidden when progress is disabled)
+    fn progress_bar(&mut self) -> &ProgressBar {
+        // Quiet mode prints nothing and verbose mode prints a line per run instead
+        let visible = self.show_progress && logging::level() == LogLevel::Normal;
+        self.progress.get_or_insert_with(|| {
+            if !visible {
+                return ProgressBar::hidden();
+            }
+
+            let bar = ProgressBar::new(0);
+            bar.set_style(
+                ProgressStyle::with_template(
+                    "    [{bar:30.cyan/blue}] {pos}/{len} runs (eta {eta})",
+                )
+                .unwrap()
+                .progress_chars("=> "),
+            );
+            logging::set_progress_bar(Some(bar.clone()));
+            bar
+        })
+    }
+
+    /// Announce `runs` upcoming runs; unless planned, they extend the bar's total
+    fn start_runs(&mut self, runs: usize) {
+        if !self.runs_planned {
+            self.progress_bar().inc_length(runs as u64);
+        }
+    }
+
+    /// Count `runs` runs as done, including ones skipped by a timeout or cancellation
+    fn finish_runs(&mut self, runs: usize) {
+        if runs == 0 {
+            return;
+        }
+        let bar = self.progress_bar();
+        bar.inc(runs as u64);
+        if bar.position() >= bar.length().unwrap_or(0) {
+            self.finish_progress();
         }
     }
 
@@ -33,56 +454,72 @@ impl BenchmarkRunner {
         memory_stats().map(|stats| stats.physical_mem)
     }
 
+    /// Growth in physical memory since `before`, or `None` if it did not grow
+    fn memory_delta(before: Option<usize>) -> Option<usize> {
+        let (before, after) = before.zip(Self::measure_memory())?;
+        (after > before).then(|| after - before)
+    }
+
     /// Benchmark sorting algorithms
     pub fn benchmark_sort(&mut self, algorithm: &str, data: &[i32], runs: usize, parallel: bool) {
+        if self.is_cancelled() {
+            return;
+        }
+
         let mut total_time = Duration::new(0, 0);
+        let mut run_times = Vec::with_capacity(runs);
         let mut memory_usage = None;
+        let mut timed_out = false;
 
-        println!("{}", format!("  Testing {}...", algorithm).cyan());
+        status!("{}", format!("  Testing {}...", algorithm).cyan());
+
+        let sort_fn = SORT_ALGORITHMS
+            .iter()
+            .find(|sort| sort.name == algorithm)
+            .unwrap_or_else(|| panic!("Unknown sorting algorithm: {}", algorithm))
+            .function(parallel)
+            .unwrap_or_else(|| panic!("{} has no parallel variant", algorithm));
+
+        self.start_runs(runs);
+
+        for _ in 0..runs {
+            if self.is_cancelled() {
+                break;
+            }
 
-        for run in 0..runs {
             let mut test_data = data.to_vec();
 
             // Start memory measurement
             let memory_before = Self::measure_memory();
 
-            let start = Instant::now();
-
-            match algorithm {
-                "Merge Sort" => {
-                    if parallel {
-                        sorting::parallel_merge_sort(&mut test_data);
-                    } else {
-                        sorting::merge_sort(&mut test_data);
-                    }
-                }
-                "Quick Sort" => {
-                    if parallel {
-                        sorting::parallel_quick_sort(&mut test_data);
-                    } else {
-                        sorting::quick_sort(&mut test_data);
-                    }
-                }
-                _ => panic!("Unknown sorting algorithm: {}", algorithm),
-            }
+            let Some((elapsed, _)) = self.run_timed(move || sort_fn(&mut test_data)) else {
+                timed_out = true;
+                break;
+            };
 
-            let elapsed = start.elapsed();
             total_time += elapsed;
+            run_times.push(elapsed);
+            detail!(
+                "    Run {}: {:.2}ms",
+                run_times.len(),
+                elapsed.as_secs_f64() * 1000.0
+            );
 
             // End memory measurement
-            if let (Some(before), Some(after)) = (memory_before, Self::measure_memory()) {
-                if after > before {
-                    memory_usage = Some(after - before);
-                }
+            if let Some(delta) = Self::memory_delta(memory_before) {
+                memory_usage = Some(delta);
             }
 
-            print!(".");
-            std::io::Write::flush(&mut std::io::stdout()).unwrap();
+            self.finish_runs(1);
         }
 
-        println!();
+        self.finish_runs(runs - run_times.len());
 
-        let avg_time = total_time / runs as u32;
+        // A timed-out benchmark reports the timeout as a lower bound
+        let avg_time = match self.timeout {
+            Some(timeout) if timed_out => timeout,
+            _ => total_time / run_times.len().max(1) as u32,
+        };
 
         let result = BenchmarkResult {
             algorithm_name: format!("{}{}", algorithm, if parallel { " (Parallel)" } else { "" }),
@@ -90,105 +527,420 @@ impl BenchmarkRunner {
             execution_time: avg_time,
             memory_used: memory_usage,
             parallel,
+            run_times,
+            timed_out,
+            output_count: None,
+            category: Category::Sorting,
         };
 
         self.results.push(result);
 
-        println!(
+        if timed_out {
+            self.print_timeout();
+            return;
+        }
+
+        status!(
             "    {}: {:.2}ms",
             if parallel { "Parallel" } else { "Sequential" },
             avg_time.as_secs_f64() * 1000.0
         );
     }
 
+    /// Benchmark an arbitrary closure for `runs` iterations
+    /// The closure runs on the calling thread, so the runner's timeout does not apply
+    /// Returns the recorded average, or `None` if no run completed before cancellation
+    pub fn benchmark_with<F: FnMut()>(
+        &mut self,
+        name: &str,
+        data_size: usize,
+        runs: usize,
+        f: F,
+    ) -> Option<Duration> {
+        self.benchmark_with_category(name, Category::Other, data_size, runs, f)
+    }
+
+    /// `benchmark_with`, recording the result under `category`
+    pub fn benchmark_with_category<F: FnMut()>(
+        &mut self,
+        name: &str,
+        category: Category,
+        data_size: usize,
+        runs: usize,
+        mut f: F,
+    ) -> Option<Duration> {
+        if self.is_cancelled() {
+            return None;
+        }
+
+        status!("{}", format!("  Testing {}...", name).cyan());
+
+        self.start_runs(runs);
+        let mut run_times = Vec::with_capacity(runs);
+        let mut memory_usage = None;
+
+        for _ in 0..runs {
+            if self.is_cancelled() {
+                break;
+            }
+
+            let memory_before = Self::measure_memory();
+            let start = Instant::now();
+
+            f();
+
+            let elapsed = start.elapsed();
+            run_times.push(elapsed);
+            detail!(
+                "    Run {}: {:.2}ms",
+                run_times.len(),
+                elapsed.as_secs_f64() * 1000.0
+            );
+
+            if let Some(delta) = Self::memory_delta(memory_before) {
+                memory_usage = Some(delta);
+            }
+
+            self.finish_runs(1);
+        }
+
+        self.finish_runs(runs - run_times.len());
+
+        if run_times.is_empty() {
+            return None;
+        }
+
+        let avg_time = run_times.iter().sum::<Duration>() / run_times.len() as u32;
+
+        self.results.push(BenchmarkResult {
+            algorithm_name: name.to_string(),
+            data_size,
+            execution_time: avg_time,
+            memory_used: memory_usage,
+            parallel: false,
+            run_times,
+            timed_out: false,
+            output_count: None,
+            category,
+        });
+
+        status!("    {}: {:.2}ms", name, avg_time.as_secs_f64() * 1000.0);
+        Some(avg_time)
+    }
+
+    /// Time two closures over the same number of runs and report which was faster
+    /// Both results are recorded like `benchmark_with` with a data size of 0; ties go to `name_a`
+    /// Fails if `runs` is 0 or the runner is cancelled before both closures have run
+    pub fn benchmark_compare<FA: FnMut(), FB: FnMut()>(
+        &mut self,
+        name_a: &str,
+        fa: FA,
+        name_b: &str,
+        fb: FB,
+        runs: usize,
+    ) -> Result<ComparisonOutcome, String> {
+        if runs == 0 {
+            return Err("Comparison requires at least one run".to_string());
+        }
+
+        let cancelled = || "Comparison cancelled".to_string();
+        let time_a = self
+            .benchmark_with(name_a, 0, runs, fa)
+            .ok_or_else(cancelled)?;
+        let time_b = self
+            .benchmark_with(name_b, 0, runs, fb)
+            .ok_or_else(cancelled)?;
+
+        let ((winner, winner_time), (loser, loser_time)) = if time_a <= time_b {
+            ((name_a, time_a), (name_b, time_b))
+        } else {
+            ((name_b, time_b), (name_a, time_a))
+        };
+        let factor = loser_time.as_secs_f64() / winner_time.as_secs_f64().max(f64::EPSILON);
+
+        status!(
+            "{}",
+            format!("  {} was {:.2}x faster than {}", winner, factor, loser).bright_green()
+        );
+
+        Ok(ComparisonOutcome {
+            winner: winner.to_string(),
+            loser: loser.to_string(),
+            factor,
+        })
+    }
+
     /// Benchmark matrix multiplication
     pub fn benchmark_matrix_multiply(
         &mut self,
         algorithm: &str,
         matrix_a: &Matrix,
         matrix_b: &Matrix,
-        use_strassen: bool,
+        method: MultiplyAlgorithm,
     ) {
-        println!("{}", format!("  Testing {}...", algorithm).cyan());
+        if self.is_cancelled() {
+            return;
+        }
+
+        status!("{}", format!("  Testing {}...", algorithm).cyan());
 
+        self.start_runs(1);
+        let (matrix_a_owned, matrix_b_owned) = (matrix_a.clone(), matrix_b.clone());
         let memory_before = Self::measure_memory();
-        let start = Instant::now();
 
-        let _result = if use_strassen {
-            crate::matrix::strassen_multiply(matrix_a, matrix_b)
-        } else {
-            crate::matrix::standard_multiply(matrix_a, matrix_b)
-        };
+        let elapsed = self
+            .run_timed(move || {
+                let _result = method.multiply(&matrix_a_owned, &matrix_b_owned);
+            })
+            .map(|(elapsed, _)| elapsed);
 
-        let elapsed = start.elapsed();
-        let memory_usage = memory_before
-            .zip(Self::measure_memory())
-            .and_then(|(before, after)| {
-                if after > before {
-                    Some(after - before)
-                } else {
-                    None
-                }
-            });
+        self.finish_runs(1);
+        let memory_usage = Self::memory_delta(memory_before);
 
         let result = BenchmarkResult {
-            algorithm_name: format!(
-                "{}{}",
-                algorithm,
-                if use_strassen {
-                    " (Strassen)"
-                } else {
-                    " (Standard)"
-                }
-            ),
+            algorithm_name: format!("{} ({})", algorithm, method.name()),
             data_size: matrix_a.size(),
-            execution_time: elapsed,
+            execution_time: elapsed.or(self.timeout).unwrap_or_default(),
             memory_used: memory_usage,
             parallel: false,
+            run_times: elapsed.into_iter().collect(),
+            timed_out: elapsed.is_none(),
+            output_count: None,
+            category: Category::Matrix,
         };
 
         self.results.push(result);
 
-        println!(
+        let Some(elapsed) = elapsed else {
+            self.print_timeout();
+            return;
+        };
+
+        status!(
             "    {}: {:.2}ms",
-            if use_strassen { "Strassen" } else { "Standard" },
+            method.name(),
             elapsed.as_secs_f64() * 1000.0
         );
     }
 
     /// Benchmark closest pair problem
     pub fn benchmark_closest_pair(&mut self, algorithm: &str, points: &[Point]) {
-        println!("{}", format!("  Testing {}...", algorithm).cyan());
+        status!("{}", format!("  Testing {}...", algorithm).cyan());
+
+        self.run_closest_pair(
+            algorithm.to_string(),
+            "Divide & Conquer",
+            points,
+            geometry::closest_pair_divide_conquer,
+        );
+    }
 
+    /// Benchmark divide-and-conquer and brute-force closest pair on the same points
+    /// Brute force is skipped above `brute_force_max` points to avoid O(n²) blowups
+    /// Returns the minimal distance found by each (divide & conquer, brute force)
+    pub fn benchmark_closest_pair_both(
+        &mut self,
+        points: &[Point],
+        brute_force_max: usize,
+    ) -> (Option<f64>, Option<f64>) {
+        status!("{}", "  Testing Closest Pair comparison...".cyan());
+
+        let divide_conquer = self.run_closest_pair(
+            "Closest Pair (Divide & Conquer)".to_string(),
+            "Divide & Conquer",
+            points,
+            geometry::closest_pair_divide_conquer,
+        );
+
+        if points.len() > brute_force_max {
+            status!(
+                "{}",
+                format!(
+                    "    Brute Force: skipped ({} points exceeds limit of {})",
+                    points.len(),
+                    brute_force_max
+                )
+                .yellow()
+            );
+            return (divide_conquer, None);
+        }
+
+        let brute_force = self.run_closest_pair(
+            "Closest Pair (Brute Force)".to_string(),
+            "Brute Force",
+            points,
+            geometry::closest_pair_brute_force,
+        );
+
+        (divide_conquer, brute_force)
+    }
+
+    /// Time one closest pair implementation and record the result
+    /// Returns the minimal distance found, or `None` on timeout, cancellation or too few points
+    fn run_closest_pair(
+        &mut self,
+        algorithm_name: String,
+        label: &str,
+        points: &[Point],
+        finder: ClosestPairFn,
+    ) -> Option<f64> {
+        if self.is_cancelled() {
+            return None;
+        }
+
+        self.start_runs(1);
+        let owned_points = points.to_vec();
         let memory_before = Self::measure_memory();
-        let start = Instant::now();
-
-        let _result = crate::geometry::closest_pair_divide_conquer(points);
-
-        let elapsed = start.elapsed();
-        let memory_usage = memory_before
-            .zip(Self::measure_memory())
-            .and_then(|(before, after)| {
-                if after > before {
-                    Some(after - before)
-                } else {
-                    None
-                }
-            });
+
+        let outcome = self.run_timed(move || finder(&owned_points).map(|r| r.distance));
+
+        self.finish_runs(1);
+        let memory_usage = Self::memory_delta(memory_before);
+
+        let elapsed = outcome.map(|(elapsed, _)| elapsed);
 
         let result = BenchmarkResult {
-            algorithm_name: algorithm.to_string(),
+            algorithm_name,
             data_size: points.len(),
-            execution_time: elapsed,
+            execution_time: elapsed.or(self.timeout).unwrap_or_default(),
             memory_used: memory_usage,
             parallel: false,
+            run_times: elapsed.into_iter().collect(),
+            timed_out: elapsed.is_none(),
+            output_count: None,
+            category: Category::Geometry,
         };
 
         self.results.push(result);
 
-        println!(
-            "    Divide & Conquer: {:.2}ms",
-            elapsed.as_secs_f64() * 1000.0
+        let Some((elapsed, distance)) = outcome else {
+            self.print_timeout();
+            return None;
+        };
+
+        status!("    {}: {:.2}ms", label, elapsed.as_secs_f64() * 1000.0);
+
+        distance
+    }
+
+    /// Benchmark convex hull computation
+    pub fn benchmark_convex_hull(&mut self, points: &[Point]) {
+        if self.is_cancelled() {
+            return;
+        }
+
+        status!("{}", "  Testing Convex Hull...".cyan());
+
+        self.start_runs(1);
+        let owned_points = points.to_vec();
+        let memory_before = Self::measure_memory();
+
+        let outcome =
+            self.run_timed(move || geometry::convex_hull_graham_scan(&owned_points).len());
+
+        self.finish_runs(1);
+        let memory_usage = Self::memory_delta(memory_before);
+
+        let elapsed = outcome.map(|(elapsed, _)| elapsed);
+        let hull_vertices = outcome.map(|(_, vertices)| vertices);
+
+        let result = BenchmarkResult {
+            algorithm_name: "Convex Hull".to_string(),
+            data_size: points.len(),
+            execution_time: elapsed.or(self.timeout).unwrap_or_default(),
+            memory_used: memory_usage,
+            parallel: false,
+            run_times: elapsed.into_iter().collect(),
+            timed_out: outcome.is_none(),
+            output_count: hull_vertices,
+            category: Category::Geometry,
+        };
+
+        self.results.push(result);
+
+        let Some((elapsed, hull_vertices)) = outcome else {
+            self.print_timeout();
+            return;
+        };
+
+        status!(
+            "    Graham Scan: {:.2}ms ({} hull vertices)",
+            elapsed.as_secs_f64() * 1000.0,
+            hull_vertices
+        );
+    }
+
+    /// Benchmark k-d tree construction and average nearest-neighbor query time
+    /// Records two results: "KdTree Build" and "KdTree Query"
+    pub fn benchmark_kdtree(&mut self, points: &[Point], queries: &[Point]) {
+        if self.is_cancelled() {
+            return;
+        }
+
+        status!("{}", "  Testing KdTree...".cyan());
+
+        self.start_runs(2);
+        let owned_points = points.to_vec();
+        let memory_before = Self::measure_memory();
+
+        let build = self.run_timed(move || KdTree::build(&owned_points));
+
+        self.finish_runs(1);
+        let memory_usage = Self::memory_delta(memory_before);
+
+        let build_elapsed = build.as_ref().map(|(elapsed, _)| *elapsed);
+        self.results.push(BenchmarkResult {
+            algorithm_name: "KdTree Build".to_string(),
+            data_size: points.len(),
+            execution_time: build_elapsed.or(self.timeout).unwrap_or_default(),
+            memory_used: memory_usage,
+            parallel: false,
+            run_times: build_elapsed.into_iter().collect(),
+            timed_out: build.is_none(),
+            output_count: None,
+            category: Category::Geometry,
+        });
+
+        let Some((build_elapsed, tree)) = build else {
+            self.finish_runs(1);
+            self.print_timeout();
+            return;
+        };
+
+        let owned_queries = queries.to_vec();
+        let query = self
+            .run_timed(move || {
+                for query in &owned_queries {
+                    let _nearest = tree.nearest_neighbor(query);
+                }
+            })
+            .map(|(elapsed, _)| elapsed / queries.len().max(1) as u32);
+
+        self.finish_runs(1);
+
+        self.results.push(BenchmarkResult {
+            algorithm_name: "KdTree Query".to_string(),
+            data_size: points.len(),
+            execution_time: query.or(self.timeout).unwrap_or_default(),
+            memory_used: None,
+            parallel: false,
+            run_times: query.into_iter().collect(),
+            timed_out: query.is_none(),
+            output_count: Some(queries.len()),
+            category: Category::Geometry,
+        });
+
+        status!("    Build: {:.2}ms", build_elapsed.as_secs_f64() * 1000.0);
+
+        let Some(query) = query else {
+            self.print_timeout();
+            return;
+        };
+
+        status!(
+            "    Query: {:.4}ms average over {} queries",
+            query.as_secs_f64() * 1000.0,
+            queries.len()
         );
     }
 
@@ -202,11 +954,11 @@ impl BenchmarkRunner {
         println!("\n{}", "=== Benchmark Results ===".bright_green().bold());
 
         // Group results by algorithm
-        let mut grouped_results = HashMap::new();
+        let mut grouped_results: HashMap<String, Vec<&BenchmarkResult>> = HashMap::new();
         for result in &self.results {
             grouped_results
                 .entry(result.algorithm_name.clone())
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(result);
         }
 
@@ -227,6 +979,39 @@ impl BenchmarkRunner {
             }
         }
 
+        let speedups = self.speedup_report();
+        if !speedups.is_empty() {
+            println!("\n{}", "--- Parallel Speedup ---".bright_yellow());
+            for entry in &speedups {
+                println!(
+                    "{} (size {}): {:.2}x ({:.2}ms sequential, {:.2}ms parallel)",
+                    entry.algorithm,
+                    entry.data_size,
+                    entry.speedup,
+                    entry.sequential_time.as_secs_f64() * 1000.0,
+                    entry.parallel_time.as_secs_f64() * 1000.0
+                );
+            }
+        }
+
+        let best = self.best_by_size();
+        if best.len() > 1 {
+            println!("\n{}", "--- Fastest per Size ---".bright_yellow());
+            let mut keys: Vec<&(Category, usize)> = best.keys().collect();
+            keys.sort();
+            for key in keys {
+                let (category, size) = key;
+                let winner = best[key];
+                println!(
+                    "{} size {}: {} ({:.2}ms)",
+                    category.name(),
+                    size,
+                    winner.algorithm_name,
+                    winner.execution_time.as_secs_f64() * 1000.0
+                );
+            }
+        }
+
         // Display best performance
         if let Some(fastest) = self.results.iter().min_by_key(|r| r.execution_time) {
             println!(
@@ -238,33 +1023,100 @@ impl BenchmarkRunner {
         }
     }
 
-    /// Save results as JSON
+    /// Fastest result at each data size within each category, ignoring timed-out runs
+    /// Sizes mean different things per category (elements, matrix dimension, points),
+    /// so results are only compared against their own category
+    pub fn best_by_size(&self) -> HashMap<(Category, usize), &BenchmarkResult> {
+        let mut best: HashMap<(Category, usize), &BenchmarkResult> = HashMap::new();
+        for result in self.results.iter().filter(|r| !r.timed_out) {
+            best.entry((result.category, result.data_size))
+                .and_modify(|current| {
+                    if result.execution_time < current.execution_time {
+                        *current = result;
+                    }
+                })
+                .or_insert(result);
+        }
+        best
+    }
+
+    /// Pair each parallel result with its sequential counterpart of the same size
+    pub fn speedup_report(&self) -> Vec<SpeedupEntry> {
+        self.results
+            .iter()
+            .filter(|r| r.parallel)
+            .filter_map(|parallel| {
+                let base = parallel
+                    .algorithm_name
+                    .strip_suffix(" (Parallel)")
+                    .unwrap_or(&parallel.algorithm_name);
+                let sequential = self.results.iter().find(|r| {
+                    !r.parallel && r.algorithm_name == base && r.data_size == parallel.data_size
+                })?;
+
+                Some(SpeedupEntry {
+                    algorithm: base.to_string(),
+                    data_size: parallel.data_size,
+                    sequential_time: sequential.execution_time,
+                    parallel_time: parallel.execution_time,
+                    speedup: sequential.execution_time.as_secs_f64()
+                        / parallel.execution_time.as_secs_f64().max(f64::EPSILON),
+                })
+            })
+            .collect()
+    }
+
+    /// Save results as versioned JSON (see `BenchmarkFile`)
     pub fn save_results(&self, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let json = serde_json::to_string_pretty(&self.results)?;
+        let file = BenchmarkFile {
+            version: RESULTS_FILE_VERSION,
+            results: self.results.clone(),
+        };
+        let json = serde_json::to_string_pretty(&file)?;
         std::fs::write(filename, json)?;
         Ok(())
     }
 
+    /// Add `duration` to the folded stack `stack` (frames separated by ';')
+    fn record_phase(&mut self, stack: &str, duration: Duration) {
+        match self
+            .phase_timings
+            .iter_mut()
+            .find(|(name, _)| name == stack)
+        {
+            Some((_, total)) => *total += duration,
+            None => self.phase_timings.push((stack.to_string(), duration)),
+        }
+    }
+
+    /// Profile where merge sort spends its time, recording recurse and merge phases
+    pub fn profile_merge_sort_phases(&mut self, data: &[i32], runs: usize) {
+        status!("{}", "  Profiling Merge Sort phases...".cyan());
+
+        for _ in 0..runs {
+            let mut test_data = data.to_vec();
+            let phases = sorting::merge_sort_instrumented(&mut test_data);
+            self.record_phase("Merge Sort;recurse", phases.recurse);
+            self.record_phase("Merge Sort;merge", phases.merge);
+        }
+    }
+
+    /// Write recorded phase timings as folded stacks ("phase;subphase count")
+    /// Counts are nanoseconds, so the file can be fed to `inferno-flamegraph`
+    pub fn export_phase_timings(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let folded: String = self
+            .phase_timings
+            .iter()
+            .map(|(stack, duration)| format!("{} {}\n", stack, duration.as_nanos()))
+            .collect();
+
+        std::fs::write(path, folded)?;
+        Ok(())
+    }
+
     /// Save results as CSV
     pub fn save_results_csv(&self, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let mut csv_content =
-            String::from("Algorithm,DataSize,ExecutionTime(ms),MemoryUsed(MB),Parallel\n");
-
-        for result in &self.results {
-            csv_content.push_str(&format!(
-                "{},{},{:.3},{},{}\n",
-                result.algorithm_name,
-                result.data_size,
-                result.execution_time.as_secs_f64() * 1000.0,
-                result.memory_used.map_or("N/A".to_string(), |m| format!(
-                    "{:.2}",
-                    m as f64 / 1024.0 / 1024.0
-                )),
-                result.parallel
-            ));
-        }
-
-        std::fs::write(filename, csv_content)?;
+        std::fs::write(filename, results_to_csv(&self.results))?;
         Ok(())
     }
 
@@ -272,4 +1124,778 @@ impl BenchmarkRunner {
     pub fn get_results(&self) -> &[BenchmarkResult] {
         &self.results
     }
+
+    /// Results matching an arbitrary predicate, in recording order
+    pub fn filter(&self, predicate: impl Fn(&BenchmarkResult) -> bool) -> Vec<&BenchmarkResult> {
+        self.results.iter().filter(|r| predicate(r)).collect()
+    }
+
+    /// Results recorded under exactly this algorithm name
+    pub fn by_algorithm(&self, name: &str) -> Vec<&BenchmarkResult> {
+        self.filter(|r| r.algorithm_name == name)
+    }
+
+    /// Results recorded for this input size
+    pub fn by_size(&self, size: usize) -> Vec<&BenchmarkResult> {
+        self.filter(|r| r.data_size == size)
+    }
+}
+
+/// Load results saved by any supported version of `save_results`
+pub fn load_results(filename: &str) -> Result<Vec<BenchmarkResult>, Box<dyn std::error::Error>> {
+    let json = std::fs::read_to_string(filename)?;
+    Ok(parse_results(&json)?)
+}
+
+/// Parse a results file, migrating older versions to the current format
+pub fn parse_results(json: &str) -> Result<Vec<BenchmarkResult>, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| format!("invalid results JSON: {}", e))?;
+
+    // Version 1 files are a bare array of results
+    if value.is_array() {
+        return serde_json::from_value(value)
+            .map_err(|e| format!("invalid version 1 results file: {}", e));
+    }
+
+    match value.get("version").and_then(serde_json::Value::as_u64) {
+        Some(version) if version == RESULTS_FILE_VERSION as u64 => {
+            let file: BenchmarkFile = serde_json::from_value(value)
+                .map_err(|e| format!("invalid version {} results file: {}", version, e))?;
+            Ok(file.results)
+        }
+        Some(version) => Err(format!(
+            "unsupported results file version {} (this build reads versions 1 to {})",
+            version, RESULTS_FILE_VERSION
+        )),
+        None => Err("results file has no version field".to_string()),
+    }
+}
+
+/// Render results in the CSV layout read back by `parse_results_csv`
+pub fn results_to_csv(results: &[BenchmarkResult]) -> String {
+    let mut csv_content =
+        String::from("Algorithm,DataSize,ExecutionTime(ms),MemoryUsed(MB),Parallel,Category\n");
+
+    for result in results {
+        csv_content.push_str(&format!(
+            "{},{},{:.3},{},{},{}\n",
+            csv_field(&result.algorithm_name),
+            result.data_size,
+            result.execution_time.as_secs_f64() * 1000.0,
+            result.memory_used.map_or("N/A".to_string(), |m| format!(
+                "{:.2}",
+                m as f64 / 1024.0 / 1024.0
+            )),
+            result.parallel,
+            result.category.name()
+        ));
+    }
+
+    csv_content
+}
+
+/// Quote a CSV field that contains a separator, quote or line break,
+/// doubling any quotes inside it
+fn csv_field(text: &str) -> std::borrow::Cow<'_, str> {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\"")).into()
+    } else {
+        text.into()
+    }
+}
+
+/// Split CSV text into records, undoing `csv_field` quoting
+/// Each record carries the line it starts on; unquoted fields are trimmed and
+/// blank lines are skipped
+fn csv_records(content: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut line_number = 1;
+    let mut record_line = 1;
+    let mut chars = content.chars().peekable();
+
+    let finish_field = |field: &mut String, quoted: &mut bool| {
+        let text = std::mem::take(field);
+        if std::mem::take(quoted) {
+            text
+        } else {
+            text.trim().to_string()
+        }
+    };
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line_number += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+
+        match c {
+            '"' if !quoted && field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+                in_quotes = true;
+            }
+            ',' => fields.push(finish_field(&mut field, &mut quoted)),
+            '\n' => {
+                fields.push(finish_field(&mut field, &mut quoted));
+                let record = std::mem::take(&mut fields);
+                if record != [""] {
+                    records.push((record_line, record));
+                }
+                line_number += 1;
+                record_line = line_number;
+            }
+            '\r' => {}
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(format!("line {}: unterminated quoted field", record_line));
+    }
+    fields.push(finish_field(&mut field, &mut quoted));
+    if fields != [""] {
+        records.push((record_line, fields));
+    }
+
+    Ok(records)
+}
+
+/// Load results written by `save_results_csv`
+pub fn load_results_csv(
+    filename: &str,
+) -> Result<Vec<BenchmarkResult>, Box<dyn std::error::Error>> {
+    let content = std::fs::read_to_string(filename)?;
+    Ok(parse_results_csv(&content)?)
+}
+
+/// Parse the CSV layout of `save_results_csv` back into results
+/// The CSV has no per-run times, so those stay empty; files written before the
+/// Category column existed get the default category. Memory is stored in MB
+/// (or "N/A") and converted back to bytes
+pub fn parse_results_csv(content: &str) -> Result<Vec<BenchmarkResult>, String> {
+    let mut results = Vec::new();
+
+    for (line_number, fields) in csv_records(content)?.into_iter().skip(1) {
+        let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+        let (name, size, time_ms, memory_mb, parallel, category) = match fields[..] {
+            [name, size, time_ms, memory_mb, parallel] => {
+                (name, size, time_ms, memory_mb, parallel, None)
+            }
+            [name, size, time_ms, memory_mb, parallel, category] => {
+                (name, size, time_ms, memory_mb, parallel, Some(category))
+            }
+            _ => {
+                return Err(format!(
+                    "line {}: expected 5 or 6 fields, found {}",
+                    line_number,
+                    fields.len()
+                ))
+            }
+        };
+
+        let invalid = |field: &str, value: &str| {
+            format!("line {}: invalid {} '{}'", line_number, field, value)
+        };
+        let data_size = size.parse().map_err(|_| invalid("data size", size))?;
+        let time_ms: f64 = time_ms
+            .parse()
+            .map_err(|_| invalid("execution time", time_ms))?;
+        let memory_used = match memory_mb {
+            "N/A" => None,
+            mb => {
+                let mb: f64 = mb.parse().map_err(|_| invalid("memory", mb))?;
+                Some((mb * 1024.0 * 1024.0) as usize)
+            }
+        };
+        let parallel = parallel
+            .parse()
+            .map_err(|_| invalid("parallel flag", parallel))?;
+        let category = match category {
+            Some(category) => category
+                .parse()
+                .map_err(|_| invalid("category", category))?,
+            None => Category::default(),
+        };
+
+        results.push(BenchmarkResult {
+            algorithm_name: name.to_string(),
+            data_size,
+            execution_time: Duration::from_secs_f64(time_ms / 1000.0),
+            memory_used,
+            parallel,
+            run_times: Vec::new(),
+            timed_out: false,
+            output_count: None,
+            category,
+        });
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_generator::DataGenerator;
+
+    fn quiet_runner() -> BenchmarkRunner {
+        let mut runner = BenchmarkRunner::new();
+        runner.set_show_progress(false);
+        runner
+    }
+
+    fn timed_result(name: &str, data_size: usize, ms: u64, parallel: bool) -> BenchmarkResult {
+        BenchmarkResult {
+            algorithm_name: name.to_string(),
+            data_size,
+            execution_time: Duration::from_millis(ms),
+            memory_used: None,
+            parallel,
+            run_times: Vec::new(),
+            timed_out: false,
+            output_count: None,
+            category: Category::Sorting,
+        }
+    }
+
+    #[test]
+    fn test_std_sort_baselines() {
+        let data = DataGenerator::generate_random_integers(3000);
+        let mut expected = data.clone();
+        sorting::merge_sort(&mut expected);
+
+        let mut runner = quiet_runner();
+        for name in ["Std Sort", "Std Stable"] {
+            for parallel in [false, true] {
+                let mut actual = data.clone();
+                let sort = SORT_ALGORITHMS.iter().find(|s| s.name == name).unwrap();
+                sort.function(parallel).unwrap()(&mut actual);
+                assert_eq!(actual, expected, "{} (parallel: {})", name, parallel);
+            }
+            runner.benchmark_sort(name, &data, 2, false);
+        }
+
+        let names: Vec<&str> = runner
+            .get_results()
+            .iter()
+            .map(|r| r.algorithm_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Std Sort", "Std Stable"]);
+    }
+
+    #[test]
+    fn test_benchmark_categories() {
+        let mut runner = quiet_runner();
+        let data = DataGenerator::generate_random_integers(200);
+        let points = DataGenerator::generate_random_points(200);
+        let (a, b) = DataGenerator::generate_random_matrices(8);
+
+        runner.benchmark_sort("Merge Sort", &data, 1, false);
+        runner.benchmark_with("Custom", 1, 1, || {});
+        runner.benchmark_matrix_multiply("Matrix", &a, &b, MultiplyAlgorithm::Standard);
+        runner.benchmark_closest_pair("Closest Pair", &points);
+        runner.benchmark_convex_hull(&points);
+        runner.benchmark_kdtree(&points, &points[..10]);
+
+        let categories: Vec<Category> = runner.get_results().iter().map(|r| r.category).collect();
+        assert_eq!(
+            categories,
+            vec![
+                Category::Sorting,
+                Category::Other,
+                Category::Matrix,
+                Category::Geometry,
+                Category::Geometry,
+                Category::Geometry,
+                Category::Geometry,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_category_defaults_for_old_results() {
+        let json = r#"{"algorithm_name":"Merge Sort","data_size":10,
+            "execution_time":{"secs":0,"nanos":5},"memory_used":null,"parallel":false}"#;
+        let result: BenchmarkResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.category, Category::Other);
+    }
+
+    #[test]
+    fn test_parse_results_csv() {
+        let csv = "Algorithm,DataSize,ExecutionTime(ms),MemoryUsed(MB),Parallel\n\
+                   Merge Sort,1000,2.500,N/A,false\n\
+                   Quick Sort (Parallel),5000,1.250,3.00,true\n";
+        let results = parse_results_csv(csv).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].algorithm_name, "Merge Sort");
+        assert_eq!(results[0].execution_time, Duration::from_micros(2500));
+        assert_eq!(results[0].memory_used, None);
+        assert_eq!(results[0].category, Category::Other);
+        assert_eq!(results[1].memory_used, Some(3 * 1024 * 1024));
+        assert!(results[1].parallel);
+
+        let with_category = "header\nStrassen,64,9.000,N/A,false,Matrix\n";
+        let results = parse_results_csv(with_category).unwrap();
+        assert_eq!(results[0].category, Category::Matrix);
+        let error = parse_results_csv("header\nStrassen,64,9.0,N/A,false,Graphs\n").unwrap_err();
+        assert!(error.contains("invalid category"));
+
+        let error = parse_results_csv("header\nMerge Sort,abc,1.0,N/A,false\n").unwrap_err();
+        assert!(error.contains("line 2"));
+        assert!(parse_results_csv("header\nMerge Sort,10\n").is_err());
+    }
+
+    #[test]
+    fn test_results_csv_quotes_names() {
+        let mut results = vec![
+            timed_result("Sort, tuned", 100, 2, false),
+            timed_result("Radix \"LSD\"", 100, 3, true),
+            timed_result("Two\nlines", 100, 4, false),
+        ];
+        results[0].memory_used = Some(2 * 1024 * 1024);
+
+        let csv = results_to_csv(&results);
+        assert!(csv.contains("\"Sort, tuned\",100,"));
+        assert!(csv.contains("\"Radix \"\"LSD\"\"\",100,"));
+
+        let loaded = parse_results_csv(&csv).unwrap();
+        assert_eq!(loaded.len(), results.len());
+        for (loaded, original) in loaded.iter().zip(&results) {
+            assert_eq!(loaded.algorithm_name, original.algorithm_name);
+            assert_eq!(loaded.execution_time, original.execution_time);
+            assert_eq!(loaded.memory_used, original.memory_used);
+            assert_eq!(loaded.parallel, original.parallel);
+        }
+
+        let error = parse_results_csv("header\n\"Sort, tuned,1,1.0,N/A,false\n").unwrap_err();
+        assert!(error.contains("line 2"));
+    }
+
+    #[test]
+    fn test_best_by_size() {
+        let mut runner = quiet_runner();
+        runner.results = vec![
+            timed_result("Merge Sort", 1000, 5, false),
+            timed_result("Quick Sort", 1000, 3, false),
+            timed_result("Merge Sort", 5000, 20, false),
+            timed_result("Quick Sort", 5000, 25, false),
+            BenchmarkResult {
+                timed_out: true,
+                ..timed_result("Bogo Sort", 5000, 1, false)
+            },
+            // A 1000x1000 multiply is not competing with sorting 1000 elements
+            BenchmarkResult {
+                category: Category::Matrix,
+                ..timed_result("Strassen", 1000, 900, false)
+            },
+        ];
+
+        let best = runner.best_by_size();
+        assert_eq!(best.len(), 3);
+        assert_eq!(
+            best[&(Category::Sorting, 1000)].algorithm_name,
+            "Quick Sort"
+        );
+        assert_eq!(
+            best[&(Category::Sorting, 5000)].algorithm_name,
+            "Merge Sort"
+        );
+        assert_eq!(best[&(Category::Matrix, 1000)].algorithm_name, "Strassen");
+    }
+
+    #[test]
+    fn test_estimate_runtime() {
+        let calibration = Duration::from_millis(10);
+
+        let quadratic = estimate_runtime(calibration, 1000, 4000, Complexity::Quadratic);
+        assert_eq!(quadratic, Duration::from_millis(160));
+
+        let cubic = estimate_runtime(calibration, 100, 200, Complexity::Cubic);
+        assert_eq!(cubic, Duration::from_millis(80));
+
+        // 1024 -> 4096 is 4x the elements and 12/10 the log factor
+        let linearithmic = estimate_runtime(calibration, 1024, 4096, Complexity::Linearithmic);
+        assert!((linearithmic.as_secs_f64() - 0.048).abs() < 1e-9);
+
+        let strassen = estimate_runtime(calibration, 64, 128, Complexity::Power(7f64.log2()));
+        assert!((strassen.as_secs_f64() - 0.070).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_analyze_complexity() {
+        let quadratic: Vec<(usize, f64)> = [1000, 2000, 5000, 10_000, 50_000]
+            .iter()
+            .map(|&n| (n, 3e-6 * (n as f64).powi(2)))
+            .collect();
+        assert!((analyze_complexity(&quadratic) - 2.0).abs() < 1e-9);
+
+        let linear = [(100, 0.5), (1000, 5.0), (0, 1.0), (10_000, 50.0)];
+        assert!((analyze_complexity(&linear) - 1.0).abs() < 1e-9);
+
+        assert!(analyze_complexity(&[(1000, 2.0), (1000, 3.0)]).is_nan());
+        assert!(analyze_complexity(&[]).is_nan());
+    }
+
+    #[test]
+    fn test_estimated_memory() {
+        let small = timed_result("Merge Sort", 10_000, 1, false);
+        let large = timed_result("Merge Sort (Parallel)", 20_000, 1, true);
+        assert_eq!(estimated_memory(&small), Some(40_000));
+        assert_eq!(
+            estimated_memory(&large),
+            estimated_memory(&small).map(|bytes| 2 * bytes)
+        );
+
+        let quick = timed_result("Quick Sort", 1 << 20, 1, false);
+        assert_eq!(estimated_memory(&quick), Some(21 * 4));
+
+        let strassen = BenchmarkResult {
+            category: Category::Matrix,
+            ..timed_result("Matrix Multiplication (Strassen)", 100, 1, false)
+        };
+        assert_eq!(estimated_memory(&strassen), Some(3 * 100 * 100 * 8));
+
+        let custom = BenchmarkResult {
+            category: Category::Other,
+            ..timed_result("Custom", 100, 1, false)
+        };
+        assert_eq!(estimated_memory(&custom), None);
+
+        // Every registered sort has a model
+        for sort in &SORT_ALGORITHMS {
+            let result = timed_result(sort.name, 1000, 1, false);
+            assert!(estimated_memory(&result).is_some(), "{}", sort.name);
+        }
+        assert_eq!(
+            estimated_memory(&timed_result("Bogo Sort", 1000, 1, false)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_load_results_versions() {
+        let v1 = r#"[{"algorithm_name":"Merge Sort","data_size":10,
+            "execution_time":{"secs":0,"nanos":5},"memory_used":null,"parallel":false}]"#;
+        let results = parse_results(v1).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].algorithm_name, "Merge Sort");
+        assert!(results[0].run_times.is_empty());
+
+        let mut runner = quiet_runner();
+        runner.results = vec![timed_result("Quick Sort", 1000, 4, false)];
+        let path = std::env::temp_dir().join("dcp_test_results_versioned.json");
+        runner.save_results(&path.to_string_lossy()).unwrap();
+        let loaded = load_results(&path.to_string_lossy()).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].category, Category::Sorting);
+
+        let error = parse_results(r#"{"version":99,"results":[]}"#).unwrap_err();
+        assert!(error.contains("unsupported results file version 99"));
+        assert!(parse_results(r#"{"results":[]}"#).is_err());
+    }
+
+    #[test]
+    fn test_export_phase_timings() {
+        let mut runner = quiet_runner();
+        runner.profile_merge_sort_phases(&DataGenerator::generate_random_integers(20_000), 2);
+
+        let path = std::env::temp_dir().join("dcp_test_phases.folded");
+        runner
+            .export_phase_timings(&path.to_string_lossy())
+            .unwrap();
+        let folded = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let lines: Vec<(&str, u128)> = folded
+            .lines()
+            .map(|line| {
+                let (stack, count) = line.rsplit_once(' ').unwrap();
+                (stack, count.parse().unwrap())
+            })
+            .collect();
+        assert_eq!(lines.len(), 2);
+        for expected in ["Merge Sort;recurse", "Merge Sort;merge"] {
+            let (_, count) = lines.iter().find(|(stack, _)| *stack == expected).unwrap();
+            assert!(*count > 0, "{} has zero count", expected);
+        }
+    }
+
+    #[test]
+    fn test_filter_by_algorithm_and_size() {
+        let mut runner = quiet_runner();
+        runner.results = vec![
+            timed_result("Merge Sort", 1000, 5, false),
+            timed_result("Quick Sort", 1000, 4, false),
+            timed_result("Merge Sort", 2000, 11, false),
+        ];
+
+        let merge = runner.by_algorithm("Merge Sort");
+        assert_eq!(merge.len(), 2);
+        assert!(merge.iter().all(|r| r.algorithm_name == "Merge Sort"));
+
+        assert_eq!(runner.by_size(1000).len(), 2);
+        assert!(runner.by_algorithm("Heap Sort").is_empty());
+        assert_eq!(runner.filter(|r| r.execution_time.as_millis() > 4).len(), 2);
+    }
+
+    #[test]
+    fn test_speedup_report() {
+        let mut runner = quiet_runner();
+        runner.results = vec![
+            timed_result("Merge Sort", 1000, 40, false),
+            timed_result("Merge Sort (Parallel)", 1000, 10, true),
+            timed_result("Quick Sort (Parallel)", 1000, 5, true),
+            timed_result("Merge Sort", 5000, 90, false),
+        ];
+
+        let report = runner.speedup_report();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].algorithm, "Merge Sort");
+        assert_eq!(report[0].data_size, 1000);
+        assert!((report[0].speedup - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_runner_without_progress_records_results() {
+        let mut runner = quiet_runner();
+        let data = DataGenerator::generate_random_integers(500);
+        let (matrix_a, matrix_b) = DataGenerator::generate_random_matrices(8);
+        let points = DataGenerator::generate_random_points(200);
+
+        runner.benchmark_sort("Merge Sort", &data, 3, false);
+        runner.benchmark_sort("Quick Sort", &data, 3, true);
+        runner.benchmark_matrix_multiply(
+            "Matrix Multiplication",
+            &matrix_a,
+            &matrix_b,
+            MultiplyAlgorithm::Standard,
+        );
+        runner.benchmark_closest_pair("Closest Pair", &points);
+
+        let results = runner.get_results();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].run_times.len(), 3);
+        assert_eq!(results[1].algorithm_name, "Quick Sort (Parallel)");
+        assert!(results.iter().all(|r| !r.timed_out));
+    }
+
+    #[test]
+    fn test_planned_progress_counts_every_benchmark() {
+        let mut runner = quiet_runner();
+        let data = DataGenerator::generate_random_integers(200);
+        let (matrix_a, matrix_b) = DataGenerator::generate_random_matrices(8);
+        let points = DataGenerator::generate_random_points(100);
+        let position =
+            |runner: &BenchmarkRunner| runner.progress.as_ref().map(|bar| bar.position());
+
+        runner.set_planned_runs(9);
+        runner.benchmark_sort("Merge Sort", &data, 3, false);
+        assert_eq!(position(&runner), Some(3));
+        runner.benchmark_matrix_multiply(
+            "Matrix",
+            &matrix_a,
+            &matrix_b,
+            MultiplyAlgorithm::Standard,
+        );
+        runner.benchmark_closest_pair("Closest Pair", &points);
+        runner.benchmark_kdtree(&points, &points[..10]);
+        assert_eq!(position(&runner), Some(7));
+        runner.benchmark_with("Custom", 1, 2, || {});
+        // Reaching the planned total clears the bar
+        assert_eq!(position(&runner), None);
+
+        // Unplanned, each benchmark clears the bar once its own runs are done
+        runner.benchmark_convex_hull(&points);
+        assert_eq!(position(&runner), None);
+        assert_eq!(runner.get_results().len(), 7);
+    }
+
+    #[test]
+    fn test_benchmark_with_closure() {
+        let mut runner = quiet_runner();
+        let mut calls = 0;
+
+        runner.benchmark_with("Custom", 42, 4, || calls += 1);
+        runner.benchmark_with_category("Sort", Category::Sorting, 7, 1, || {});
+
+        assert_eq!(calls, 4);
+        let results = runner.get_results();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].algorithm_name, "Custom");
+        assert_eq!(results[0].data_size, 42);
+        assert_eq!(results[0].run_times.len(), 4);
+        assert_eq!(results[0].category, Category::Other);
+        assert_eq!(results[1].category, Category::Sorting);
+    }
+
+    #[test]
+    fn test_benchmark_compare_picks_faster() {
+        let mut runner = quiet_runner();
+
+        let outcome = runner
+            .benchmark_compare(
+                "Slow",
+                || std::thread::sleep(Duration::from_millis(5)),
+                "Fast",
+                || {},
+                3,
+            )
+            .unwrap();
+
+        assert_eq!(outcome.winner, "Fast");
+        assert_eq!(outcome.loser, "Slow");
+        assert!(outcome.factor > 1.0);
+        assert_eq!(runner.get_results().len(), 2);
+        assert_eq!(runner.by_algorithm("Slow")[0].run_times.len(), 3);
+    }
+
+    #[test]
+    fn test_benchmark_compare_rejects_zero_runs_and_cancellation() {
+        let mut runner = quiet_runner();
+        assert!(runner.benchmark_compare("A", || {}, "B", || {}, 0).is_err());
+        assert!(runner.get_results().is_empty());
+
+        // A result recorded before the comparison must not be mistaken for "B"
+        runner.benchmark_with("Earlier", 0, 1, || {});
+        let flag = Arc::new(AtomicBool::new(false));
+        runner.set_cancel_flag(Arc::clone(&flag));
+        let outcome =
+            runner.benchmark_compare("A", || flag.store(true, Ordering::Relaxed), "B", || {}, 3);
+
+        assert_eq!(outcome, Err("Comparison cancelled".to_string()));
+        assert!(runner.by_algorithm("B").is_empty());
+    }
+
+    #[test]
+    fn test_cancel_flag_keeps_partial_results() {
+        let mut runner = quiet_runner();
+        let flag = Arc::new(AtomicBool::new(false));
+        runner.set_cancel_flag(Arc::clone(&flag));
+
+        let data = DataGenerator::generate_random_integers(1000);
+        runner.benchmark_sort("Merge Sort", &data, 2, false);
+
+        let mut calls = 0;
+        runner.benchmark_with("Interrupted", 1000, 5, || {
+            calls += 1;
+            if calls == 2 {
+                flag.store(true, Ordering::Relaxed);
+            }
+        });
+        runner.benchmark_sort("Quick Sort", &data, 2, false);
+
+        assert!(runner.is_cancelled());
+        assert_eq!(calls, 2);
+        let results = runner.get_results();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].run_times.len(), 2);
+        assert_eq!(results[1].algorithm_name, "Interrupted");
+        assert_eq!(results[1].run_times.len(), 2);
+    }
+
+    #[test]
+    fn test_run_timed_abandons_slow_work() {
+        let mut runner = quiet_runner();
+        runner.set_timeout(Some(Duration::from_millis(5)));
+        assert!(runner
+            .run_timed(|| thread::sleep(Duration::from_millis(100)))
+            .is_none());
+
+        runner.set_timeout(Some(Duration::from_secs(5)));
+        let (_, output) = runner.run_timed(|| 7).unwrap();
+        assert_eq!(output, 7);
+    }
+
+    #[test]
+    fn test_timeout_records_timed_out_result() {
+        let mut runner = quiet_runner();
+        runner.set_timeout(Some(Duration::from_micros(1)));
+
+        // Small inputs still take far longer than the timeout, and the abandoned
+        // threads finish within milliseconds instead of burning CPU for seconds
+        let data = DataGenerator::generate_random_integers(20_000);
+        runner.benchmark_sort("Merge Sort", &data, 5, false);
+
+        let points = DataGenerator::generate_random_points(5_000);
+        runner.benchmark_closest_pair("Closest Pair", &points);
+
+        let results = runner.get_results();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.timed_out));
+        assert!(results[0].run_times.is_empty());
+        assert_eq!(results[0].execution_time, Duration::from_micros(1));
+    }
+
+    #[test]
+    fn test_convex_hull_benchmark_circle() {
+        let mut runner = quiet_runner();
+        let points = DataGenerator::generate_circular_points(100, 50.0);
+
+        runner.benchmark_convex_hull(&points);
+
+        let results = runner.get_results();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].data_size, 100);
+        assert_eq!(results[0].output_count, Some(100));
+    }
+
+    #[test]
+    fn test_kdtree_benchmark_records_build_and_query() {
+        let mut runner = quiet_runner();
+        let points = DataGenerator::generate_random_points(1000);
+        let queries = DataGenerator::generate_random_points(50);
+
+        runner.benchmark_kdtree(&points, &queries);
+
+        let results = runner.get_results();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].algorithm_name, "KdTree Build");
+        assert_eq!(results[1].algorithm_name, "KdTree Query");
+        assert!(results.iter().all(|r| r.data_size == 1000));
+    }
+
+    #[test]
+    fn test_closest_pair_both_agree() {
+        let mut runner = quiet_runner();
+        let points = DataGenerator::generate_random_points(500);
+
+        let (divide_conquer, brute_force) = runner.benchmark_closest_pair_both(&points, 1000);
+
+        let results = runner.get_results();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].algorithm_name, "Closest Pair (Divide & Conquer)");
+        assert_eq!(results[1].algorithm_name, "Closest Pair (Brute Force)");
+        assert!((divide_conquer.unwrap() - brute_force.unwrap()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_closest_pair_both_caps_brute_force() {
+        let mut runner = quiet_runner();
+        let points = DataGenerator::generate_random_points(500);
+
+        let (divide_conquer, brute_force) = runner.benchmark_closest_pair_both(&points, 100);
+
+        assert!(divide_conquer.is_some());
+        assert!(brute_force.is_none());
+        assert_eq!(runner.get_results().len(), 1);
+    }
 }
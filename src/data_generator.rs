@@ -1,17 +1,150 @@
 use crate::geometry::Point;
 use crate::matrix::Matrix;
+use crate::sorting;
 use rand::prelude::*;
-use rand::rng;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+
+thread_local! {
+    /// Seeded source used while inside `DataGenerator::with_seed`
+    static SEEDED_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Get a generator for one dataset, derived from the active seed if any
+fn rng() -> StdRng {
+    SEEDED_RNG.with(|cell| match cell.borrow_mut().as_mut() {
+        Some(seeded) => StdRng::seed_from_u64(seeded.next_u64()),
+        None => StdRng::from_rng(&mut rand::rng()),
+    })
+}
+
+/// Puts back the seeded source that was active before `DataGenerator::with_seed`
+struct SeedGuard {
+    previous: Option<StdRng>,
+}
+
+impl Drop for SeedGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        SEEDED_RNG.with(|cell| *cell.borrow_mut() = previous);
+    }
+}
 
 pub struct DataGenerator;
 
 impl DataGenerator {
-    /// Generate random integer array
+    /// Run `f` with all data generation on this thread seeded from `seed`
+    /// The previous seed is restored even if `f` panics
+    pub fn with_seed<R>(seed: u64, f: impl FnOnce() -> R) -> R {
+        let _restore = SeedGuard {
+            previous: SEEDED_RNG
+                .with(|cell| cell.borrow_mut().replace(StdRng::seed_from_u64(seed))),
+        };
+        f()
+    }
+
+    /// Load integers from a file, separated by newlines and/or commas
+    pub fn load_integers(path: &str) -> Result<Vec<i32>, Box<dyn Error>> {
+        let content = fs::read_to_string(path)?;
+        Ok(Self::parse_integers(&content)?)
+    }
+
+    /// Save integers one per line, in the format `load_integers` reads
+    pub fn save_integers(path: &str, values: &[i32]) -> Result<(), Box<dyn Error>> {
+        let content: String = values.iter().map(|v| format!("{}\n", v)).collect();
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Parse newline- or comma-separated integers, reporting the offending line on error
+    pub fn parse_integers(content: &str) -> Result<Vec<i32>, String> {
+        let mut values = Vec::new();
+
+        for (line_number, line) in content.lines().enumerate() {
+            for token in line.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+                let value = token.parse::<i32>().map_err(|_| {
+                    format!("line {}: invalid integer '{}'", line_number + 1, token)
+                })?;
+                values.push(value);
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Load points from a CSV file of `x,y` lines, skipping a header row if present
+    pub fn load_points_csv(path: &str) -> Result<Vec<Point>, Box<dyn Error>> {
+        let content = fs::read_to_string(path)?;
+        Ok(Self::parse_points_csv(&content)?)
+    }
+
+    /// Save points as `x,y` lines, in the format `load_points_csv` reads
+    /// Coordinates use Rust's shortest round-trip formatting, so loading is exact
+    pub fn save_points_csv(path: &str, points: &[Point]) -> Result<(), Box<dyn Error>> {
+        let mut content = String::from("x,y\n");
+        for point in points {
+            content.push_str(&format!("{},{}\n", point.x, point.y));
+        }
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Parse `x,y` lines into points, reporting the offending line on error
+    pub fn parse_points_csv(content: &str) -> Result<Vec<Point>, String> {
+        let mut points = Vec::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let parsed = match fields.as_slice() {
+                [x, y] => x.parse::<f64>().ok().zip(y.parse::<f64>().ok()),
+                _ => None,
+            };
+
+            match parsed {
+                Some((x, y)) => points.push(Point::new(x, y)),
+                // A non-numeric first line is treated as a header
+                None if index == 0 => continue,
+                None => {
+                    return Err(format!(
+                        "line {}: expected 'x,y' with numeric values, got '{}'",
+                        index + 1,
+                        line
+                    ))
+                }
+            }
+        }
+
+        Ok(points)
+    }
+
+    /// Generate random integer array in the default range -1,000,000..=1,000,000
     pub fn generate_random_integers(size: usize) -> Vec<i32> {
+        Self::generate_random_integers_range(size, -1000000, 1000000)
+            .expect("default range is valid")
+    }
+
+    /// Generate random integers uniformly in `min..=max`; requires `min < max`
+    pub fn generate_random_integers_range(
+        size: usize,
+        min: i32,
+        max: i32,
+    ) -> Result<Vec<i32>, String> {
+        if min >= max {
+            return Err(format!(
+                "Invalid range: min ({}) must be less than max ({})",
+                min, max
+            ));
+        }
+
         let mut rng = rng();
-        (0..size)
-            .map(|_| rng.random_range(-1000000..=1000000))
-            .collect()
+        Ok((0..size).map(|_| rng.random_range(min..=max)).collect())
     }
 
     /// Generate sorted array (for worst case testing)
@@ -24,6 +157,20 @@ impl DataGenerator {
         (0..size as i32).rev().collect()
     }
 
+    /// Generate the permutation that drives a last-element (Lomuto) pivot, as
+    /// used by `quickselect`, to its quadratic worst case: 1, 2, ..., size-1, 0
+    ///
+    /// Every partition picks the minimum of its range as pivot, and swapping it
+    /// to the front moves the next-smallest value to the end, so each level
+    /// peels off a single element and the pattern repeats on the remainder
+    pub fn generate_quicksort_killer(size: usize) -> Vec<i32> {
+        let mut data: Vec<i32> = (1..size as i32).collect();
+        if size > 0 {
+            data.push(0);
+        }
+        data
+    }
+
     /// Generate partially sorted array
     pub fn generate_partially_sorted_integers(size: usize, sorted_ratio: f64) -> Vec<i32> {
         let mut data = Self::generate_random_integers(size);
@@ -112,6 +259,69 @@ impl DataGenerator {
         points
     }
 
+    /// Generate up to `count` points inside `bounds` (min corner, max corner) with
+    /// every pair at least `min_distance` apart, by rejecting candidates that land too
+    /// close to an accepted point (Poisson-disk dart throwing)
+    ///
+    /// Gives up after `POISSON_DISK_ATTEMPTS` consecutive rejections, so a region too
+    /// small to fit `count` points yields fewer points rather than looping forever
+    pub fn generate_poisson_disk_points(
+        count: usize,
+        min_distance: f64,
+        bounds: (Point, Point),
+    ) -> Vec<Point> {
+        const POISSON_DISK_ATTEMPTS: usize = 1000;
+
+        let mut rng = rng();
+        let (min_x, max_x) = (bounds.0.x.min(bounds.1.x), bounds.0.x.max(bounds.1.x));
+        let (min_y, max_y) = (bounds.0.y.min(bounds.1.y), bounds.0.y.max(bounds.1.y));
+        let mut candidate = || {
+            Point::new(
+                rng.random_range(min_x..=max_x),
+                rng.random_range(min_y..=max_y),
+            )
+        };
+
+        if min_distance <= 0.0 {
+            return (0..count).map(|_| candidate()).collect();
+        }
+
+        // Cells of side min_distance / sqrt(2) hold at most one accepted point,
+        // so only the surrounding 5x5 block of cells needs checking
+        let cell_size = min_distance / std::f64::consts::SQRT_2;
+        let cell_of = |p: &Point| {
+            (
+                ((p.x - min_x) / cell_size) as i64,
+                ((p.y - min_y) / cell_size) as i64,
+            )
+        };
+        let min_distance_squared = min_distance * min_distance;
+
+        let mut grid: HashMap<(i64, i64), Point> = HashMap::new();
+        let mut points = Vec::with_capacity(count);
+        let mut failures = 0;
+
+        while points.len() < count && failures < POISSON_DISK_ATTEMPTS {
+            let point = candidate();
+            let (cx, cy) = cell_of(&point);
+
+            let too_close = (cx - 2..=cx + 2)
+                .flat_map(|x| (cy - 2..=cy + 2).map(move |y| (x, y)))
+                .filter_map(|cell| grid.get(&cell))
+                .any(|other| point.distance_squared_to(other) < min_distance_squared);
+
+            if too_close {
+                failures += 1;
+            } else {
+                grid.insert((cx, cy), point);
+                points.push(point);
+                failures = 0;
+            }
+        }
+
+        points
+    }
+
     /// Generate random square matrix pair
     pub fn generate_random_matrices(size: usize) -> (Matrix, Matrix) {
         let mut rng = rng();
@@ -132,6 +342,28 @@ impl DataGenerator {
         (matrix_a, matrix_b)
     }
 
+    /// Generate a random rows x cols matrix
+    pub fn generate_random_matrix_rect(rows: usize, cols: usize) -> Matrix {
+        let mut rng = rng();
+        Matrix::from_vec(
+            (0..rows)
+                .map(|_| {
+                    (0..cols)
+                        .map(|_| rng.random_range(-100.0..=100.0))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    /// Generate an m x n and an n x p matrix, which can be multiplied together
+    pub fn generate_multipliable_pair(m: usize, n: usize, p: usize) -> (Matrix, Matrix) {
+        (
+            Self::generate_random_matrix_rect(m, n),
+            Self::generate_random_matrix_rect(n, p),
+        )
+    }
+
     /// Generate identity matrix
     pub fn generate_identity_matrix(size: usize) -> Matrix {
         Matrix::new(size, |i, j| if i == j { 1.0 } else { 0.0 })
@@ -167,6 +399,40 @@ impl DataGenerator {
         Matrix::new(size, |i, j| if i == j { diagonal_values[i] } else { 0.0 })
     }
 
+    /// Summary statistics of an integer dataset; the median comes from
+    /// `quickselect` on a copy, so no full sort is needed
+    /// An empty dataset yields zero min/max and NaN mean/median
+    pub fn dataset_stats(arr: &[i32]) -> DatasetStats {
+        if arr.is_empty() {
+            return DatasetStats {
+                count: 0,
+                min: 0,
+                max: 0,
+                mean: f64::NAN,
+                median: f64::NAN,
+            };
+        }
+
+        let mut scratch = arr.to_vec();
+        let mid = arr.len() / 2;
+        let upper = sorting::quickselect(&mut scratch, mid).unwrap() as f64;
+        // quickselect leaves everything before `mid` no larger than the pivot
+        let median = if arr.len().is_multiple_of(2) {
+            let lower = *scratch[..mid].iter().max().unwrap() as f64;
+            (lower + upper) / 2.0
+        } else {
+            upper
+        };
+
+        DatasetStats {
+            count: arr.len(),
+            min: *arr.iter().min().unwrap(),
+            max: *arr.iter().max().unwrap(),
+            mean: arr.iter().map(|&v| v as f64).sum::<f64>() / arr.len() as f64,
+            median,
+        }
+    }
+
     /// Generate test datasets for testing
     pub fn generate_test_datasets() -> TestDatasets {
         TestDatasets {
@@ -186,6 +452,79 @@ impl DataGenerator {
     }
 }
 
+/// Shape of generated integer input, selectable from the CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataPattern {
+    Random,
+    Sorted,
+    Reverse,
+    Duplicates,
+    /// First half sorted, remainder random
+    Partial,
+}
+
+impl DataPattern {
+    pub const ALL: [DataPattern; 5] = [
+        DataPattern::Random,
+        DataPattern::Sorted,
+        DataPattern::Reverse,
+        DataPattern::Duplicates,
+        DataPattern::Partial,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DataPattern::Random => "random",
+            DataPattern::Sorted => "sorted",
+            DataPattern::Reverse => "reverse",
+            DataPattern::Duplicates => "duplicates",
+            DataPattern::Partial => "partial",
+        }
+    }
+
+    /// Generate `size` integers with this pattern
+    pub fn generate(&self, size: usize) -> Vec<i32> {
+        match self {
+            DataPattern::Random => DataGenerator::generate_random_integers(size),
+            DataPattern::Sorted => DataGenerator::generate_sorted_integers(size),
+            DataPattern::Reverse => DataGenerator::generate_reverse_sorted_integers(size),
+            DataPattern::Duplicates => {
+                DataGenerator::generate_duplicate_heavy_integers(size, (size / 100).max(1))
+            }
+            DataPattern::Partial => DataGenerator::generate_partially_sorted_integers(size, 0.5),
+        }
+    }
+}
+
+impl std::str::FromStr for DataPattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_ascii_lowercase();
+        DataPattern::ALL
+            .into_iter()
+            .find(|pattern| pattern.name() == lower)
+            .ok_or_else(|| {
+                let choices: Vec<&str> = DataPattern::ALL.iter().map(|p| p.name()).collect();
+                format!(
+                    "unknown pattern '{}' (expected one of: {})",
+                    s,
+                    choices.join(", ")
+                )
+            })
+    }
+}
+
+/// Summary statistics of an integer dataset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DatasetStats {
+    pub count: usize,
+    pub min: i32,
+    pub max: i32,
+    pub mean: f64,
+    pub median: f64,
+}
+
 /// Collection of test datasets
 pub struct TestDatasets {
     pub small_integers: Vec<i32>,
@@ -208,9 +547,17 @@ impl TestDatasets {
         println!("=== Test Dataset Statistics ===");
 
         println!("Integer data:");
-        println!("  Small: {} elements", self.small_integers.len());
-        println!("  Medium: {} elements", self.medium_integers.len());
-        println!("  Large: {} elements", self.large_integers.len());
+        for (name, data) in [
+            ("Small", &self.small_integers),
+            ("Medium", &self.medium_integers),
+            ("Large", &self.large_integers),
+        ] {
+            let stats = DataGenerator::dataset_stats(data);
+            println!(
+                "  {}: {} elements (min {}, max {}, mean {:.2}, median {:.1})",
+                name, stats.count, stats.min, stats.max, stats.mean, stats.median
+            );
+        }
 
         println!("Point data:");
         println!("  Random: {} points", self.random_points.len());
@@ -235,3 +582,191 @@ impl TestDatasets {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_seed_is_reproducible() {
+        let first = DataGenerator::with_seed(42, || DataGenerator::generate_random_integers(1000));
+        let second = DataGenerator::with_seed(42, || DataGenerator::generate_random_integers(1000));
+        let other = DataGenerator::with_seed(43, || DataGenerator::generate_random_integers(1000));
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_with_seed_restored_after_panic() {
+        let panicked =
+            std::panic::catch_unwind(|| DataGenerator::with_seed(7, || panic!("generator failed")));
+        assert!(panicked.is_err());
+        assert!(SEEDED_RNG.with(|cell| cell.borrow().is_none()));
+
+        // Nested seeds come back to the outer one
+        let outer = DataGenerator::with_seed(42, || {
+            let _ = std::panic::catch_unwind(|| DataGenerator::with_seed(7, || panic!("inner")));
+            DataGenerator::generate_random_integers(100)
+        });
+        let expected =
+            DataGenerator::with_seed(42, || DataGenerator::generate_random_integers(100));
+        assert_eq!(outer, expected);
+    }
+
+    #[test]
+    fn test_data_patterns() {
+        for pattern in DataPattern::ALL {
+            assert_eq!(pattern.name().parse::<DataPattern>(), Ok(pattern));
+            assert_eq!(pattern.generate(2000).len(), 2000, "{}", pattern.name());
+        }
+
+        let sorted = DataPattern::Sorted.generate(500);
+        assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+        let reverse = DataPattern::Reverse.generate(500);
+        assert!(reverse.windows(2).all(|w| w[0] >= w[1]));
+        let partial = DataPattern::Partial.generate(500);
+        assert!(partial[..250].windows(2).all(|w| w[0] <= w[1]));
+
+        let duplicates = DataPattern::Duplicates.generate(2000);
+        assert!(sorting::count_distinct(&duplicates) <= 20);
+
+        assert!("zigzag".parse::<DataPattern>().is_err());
+    }
+
+    #[test]
+    fn test_poisson_disk_points_are_separated() {
+        let bounds = (Point::new(0.0, 0.0), Point::new(100.0, 100.0));
+        let points = DataGenerator::generate_poisson_disk_points(500, 3.0, bounds);
+
+        assert_eq!(points.len(), 500);
+        for (i, a) in points.iter().enumerate() {
+            assert!((0.0..=100.0).contains(&a.x) && (0.0..=100.0).contains(&a.y));
+            for b in &points[i + 1..] {
+                assert!(a.distance_to(b) >= 3.0, "{:?} and {:?} too close", a, b);
+            }
+        }
+
+        // Only a handful of points fit; the retry cap stops generation early
+        let crowded = DataGenerator::generate_poisson_disk_points(
+            100,
+            6.0,
+            (bounds.0, Point::new(10.0, 10.0)),
+        );
+        assert!(crowded.len() < 100);
+    }
+
+    #[test]
+    fn test_dataset_stats() {
+        let stats = DataGenerator::dataset_stats(&[7, -2, 9, 4, 4, 10]);
+        assert_eq!(stats.count, 6);
+        assert_eq!(stats.min, -2);
+        assert_eq!(stats.max, 10);
+        assert!((stats.mean - 32.0 / 6.0).abs() < 1e-12);
+        assert_eq!(stats.median, 5.5);
+
+        let odd = DataGenerator::dataset_stats(&[3, 1, 2]);
+        assert_eq!(odd.median, 2.0);
+
+        let empty = DataGenerator::dataset_stats(&[]);
+        assert_eq!(empty.count, 0);
+        assert!(empty.median.is_nan());
+    }
+
+    #[test]
+    fn test_generate_multipliable_pair() {
+        let (a, b) = DataGenerator::generate_multipliable_pair(7, 3, 5);
+        assert_eq!((a.rows(), a.cols()), (7, 3));
+        assert_eq!((b.rows(), b.cols()), (3, 5));
+
+        let product = crate::matrix::standard_multiply(&a, &b).unwrap();
+        assert_eq!((product.rows(), product.cols()), (7, 5));
+    }
+
+    #[test]
+    fn test_generate_quicksort_killer() {
+        let data = DataGenerator::generate_quicksort_killer(2000);
+
+        let mut sorted = data.clone();
+        crate::sorting::quick_sort(&mut sorted);
+        assert_eq!(sorted, (0..2000).collect::<Vec<i32>>());
+
+        assert!(DataGenerator::generate_quicksort_killer(0).is_empty());
+        assert_eq!(DataGenerator::generate_quicksort_killer(1), vec![0]);
+    }
+
+    #[test]
+    fn test_generate_random_integers_range() {
+        let values = DataGenerator::generate_random_integers_range(5000, -3, 7).unwrap();
+        assert_eq!(values.len(), 5000);
+        assert!(values.iter().all(|&v| (-3..=7).contains(&v)));
+        assert!(values.contains(&-3) && values.contains(&7));
+
+        assert!(DataGenerator::generate_random_integers_range(10, 5, 5).is_err());
+        assert!(DataGenerator::generate_random_integers_range(10, 6, 5).is_err());
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let mut integers = DataGenerator::generate_random_integers(1000);
+        integers.extend([i32::MIN, i32::MAX, 0]);
+        let mut points = DataGenerator::generate_random_points(500);
+        points.push(Point::new(0.1 + 0.2, -1e-300));
+
+        let int_path = std::env::temp_dir().join("dcp_test_save_integers.txt");
+        let int_path = int_path.to_string_lossy();
+        DataGenerator::save_integers(&int_path, &integers).unwrap();
+        let loaded_integers = DataGenerator::load_integers(&int_path).unwrap();
+        let _ = fs::remove_file(int_path.as_ref());
+        assert_eq!(loaded_integers, integers);
+
+        let point_path = std::env::temp_dir().join("dcp_test_save_points.csv");
+        let point_path = point_path.to_string_lossy();
+        DataGenerator::save_points_csv(&point_path, &points).unwrap();
+        let loaded_points = DataGenerator::load_points_csv(&point_path).unwrap();
+        let _ = fs::remove_file(point_path.as_ref());
+        assert_eq!(loaded_points, points);
+    }
+
+    #[test]
+    fn test_load_integers() {
+        let path = std::env::temp_dir().join("dcp_test_integers.txt");
+        fs::write(&path, "5, -3\n12\n\n7,0,\n").unwrap();
+
+        let values = DataGenerator::load_integers(&path.to_string_lossy()).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(values, vec![5, -3, 12, 7, 0]);
+    }
+
+    #[test]
+    fn test_load_points_csv() {
+        let path = std::env::temp_dir().join("dcp_test_points.csv");
+        fs::write(&path, "x,y\n1.5,2\n-3,4.25\n\n0,0\n").unwrap();
+
+        let points = DataGenerator::load_points_csv(&path.to_string_lossy()).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            points,
+            vec![
+                Point::new(1.5, 2.0),
+                Point::new(-3.0, 4.25),
+                Point::new(0.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_points_csv_reports_bad_line() {
+        let error = DataGenerator::parse_points_csv("1,2\n3,4\n5\n").unwrap_err();
+        assert!(error.contains("line 3"));
+    }
+
+    #[test]
+    fn test_parse_integers_reports_bad_line() {
+        let error = DataGenerator::parse_integers("1\n2\nabc\n4").unwrap_err();
+        assert!(error.contains("line 3"));
+        assert!(error.contains("abc"));
+    }
+}
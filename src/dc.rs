@@ -0,0 +1,77 @@
+/// A problem solved by splitting into two halves and combining the results
+/// Implementors describe one level of the recursion; `solve` drives it
+pub trait DivideConquer: Sync {
+    type Problem: Send;
+    type Solution: Send;
+
+    /// Whether the problem is small enough to solve directly
+    fn is_base(&self, problem: &Self::Problem) -> bool;
+
+    /// Solve a base-case problem directly
+    fn solve_base(&self, problem: Self::Problem) -> Self::Solution;
+
+    /// Split a problem into two independent subproblems
+    fn divide(&self, problem: Self::Problem) -> (Self::Problem, Self::Problem);
+
+    /// Merge the solutions of the two subproblems
+    fn combine(&self, left: Self::Solution, right: Self::Solution) -> Self::Solution;
+}
+
+/// Recursively solve `problem`, running both halves on Rayon until
+/// `parallel_depth` levels have been split (0 solves sequentially)
+pub fn solve<D: DivideConquer>(dc: &D, problem: D::Problem, parallel_depth: usize) -> D::Solution {
+    if dc.is_base(&problem) {
+        return dc.solve_base(problem);
+    }
+
+    let (left, right) = dc.divide(problem);
+    let (left, right) = if parallel_depth > 0 {
+        rayon::join(
+            || solve(dc, left, parallel_depth - 1),
+            || solve(dc, right, parallel_depth - 1),
+        )
+    } else {
+        (solve(dc, left, 0), solve(dc, right, 0))
+    };
+
+    dc.combine(left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sums a range by halving it, counting the base cases it reaches
+    struct RangeSum;
+
+    impl DivideConquer for RangeSum {
+        type Problem = std::ops::Range<u64>;
+        type Solution = (u64, usize);
+
+        fn is_base(&self, problem: &Self::Problem) -> bool {
+            problem.end - problem.start <= 4
+        }
+
+        fn solve_base(&self, problem: Self::Problem) -> Self::Solution {
+            (problem.sum(), 1)
+        }
+
+        fn divide(&self, problem: Self::Problem) -> (Self::Problem, Self::Problem) {
+            let mid = problem.start + (problem.end - problem.start) / 2;
+            (problem.start..mid, mid..problem.end)
+        }
+
+        fn combine(&self, left: Self::Solution, right: Self::Solution) -> Self::Solution {
+            (left.0 + right.0, left.1 + right.1)
+        }
+    }
+
+    #[test]
+    fn test_solve_sequential_and_parallel_agree() {
+        let expected = (0..1000u64).sum::<u64>();
+
+        assert_eq!(solve(&RangeSum, 0..1000, 0), (expected, 256));
+        assert_eq!(solve(&RangeSum, 0..1000, 3), (expected, 256));
+        assert_eq!(solve(&RangeSum, 0..3, 3), (3, 1));
+    }
+}
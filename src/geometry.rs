@@ -1,5 +1,8 @@
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::parallel;
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Point {
     pub x: f64,
@@ -26,16 +29,137 @@ impl Point {
     }
 }
 
+/// Single-precision point for memory-constrained workloads
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point32 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Point32 {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+    
+    /// Calculate Euclidean distance between two points
+    pub fn distance_to(&self, other: &Point32) -> f32 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        (dx * dx + dy * dy).sqrt()
+    }
+    
+    /// Calculate squared distance (faster for comparisons)
+    pub fn distance_squared_to(&self, other: &Point32) -> f32 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        dx * dx + dy * dy
+    }
+}
+
+impl From<Point> for Point32 {
+    fn from(point: Point) -> Self {
+        Self::new(point.x as f32, point.y as f32)
+    }
+}
+
+/// 2D point types accepted by the closest pair algorithms
+/// Coordinates are widened to f64 for the distance computations; the divide and
+/// conquer search also needs `Send` to split large inputs across threads
+pub trait PlanarPoint: Copy {
+    fn x(&self) -> f64;
+    fn y(&self) -> f64;
+    
+    fn planar_distance(&self, other: &Self) -> f64 {
+        self.planar_distance_squared(other).sqrt()
+    }
+    
+    fn planar_distance_squared(&self, other: &Self) -> f64 {
+        let dx = self.x() - other.x();
+        let dy = self.y() - other.y();
+        dx * dx + dy * dy
+    }
+}
+
+impl PlanarPoint for Point {
+    fn x(&self) -> f64 {
+        self.x
+    }
+    
+    fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+impl PlanarPoint for Point32 {
+    fn x(&self) -> f64 {
+        self.x as f64
+    }
+    
+    fn y(&self) -> f64 {
+        self.y as f64
+    }
+}
+
+/// A point carrying an arbitrary payload such as a record id
 #[derive(Debug, Clone, PartialEq)]
-pub struct ClosestPairResult {
-    pub point1: Point,
-    pub point2: Point,
+pub struct LabeledPoint<T> {
+    pub point: Point,
+    pub label: T,
+}
+
+impl<T> LabeledPoint<T> {
+    pub fn new(point: Point, label: T) -> Self {
+        Self { point, label }
+    }
+}
+
+/// Point tagged with its index in the caller's slice, so payloads need not be `Copy`
+#[derive(Debug, Clone, Copy)]
+struct IndexedPoint {
+    point: Point,
+    index: usize,
+}
+
+impl PlanarPoint for IndexedPoint {
+    fn x(&self) -> f64 {
+        self.point.x
+    }
+    
+    fn y(&self) -> f64 {
+        self.point.y
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosestPairResult<P = Point> {
+    pub point1: P,
+    pub point2: P,
     pub distance: f64,
 }
 
 /// Brute force approach to find closest pair of points
-/// Time complexity: O(n²)
-pub fn closest_pair_brute_force(points: &[Point]) -> Option<ClosestPairResult> {
+/// Time complexity: O(n²); returns `None` for fewer than 2 points
+pub fn closest_pair_brute_force<P: PlanarPoint>(points: &[P]) -> Option<ClosestPairResult<P>> {
+    brute_force_counting(points, &mut 0).map(with_euclidean_distance)
+}
+
+/// Brute force closest pair, also returning the number of distance computations
+/// The count is always n * (n - 1) / 2
+pub fn closest_pair_brute_force_counted<P: PlanarPoint>(points: &[P]) -> Option<(ClosestPairResult<P>, u64)> {
+    let mut comparisons = 0;
+    brute_force_counting(points, &mut comparisons).map(|result| (with_euclidean_distance(result), comparisons))
+}
+
+/// Convert an internal result carrying a squared distance into the public form
+fn with_euclidean_distance<P>(result: ClosestPairResult<P>) -> ClosestPairResult<P> {
+    ClosestPairResult {
+        distance: result.distance.sqrt(),
+        ..result
+    }
+}
+
+/// Brute force search; the returned `distance` is squared
+fn brute_force_counting<P: PlanarPoint>(points: &[P], comparisons: &mut u64) -> Option<ClosestPairResult<P>> {
     if points.len() < 2 {
         return None;
     }
@@ -45,7 +169,8 @@ pub fn closest_pair_brute_force(points: &[Point]) -> Option<ClosestPairResult> {
     
     for i in 0..points.len() {
         for j in (i + 1)..points.len() {
-            let distance = points[i].distance_to(&points[j]);
+            let distance = points[i].planar_distance_squared(&points[j]);
+            *comparisons += 1;
             if distance < min_distance {
                 min_distance = distance;
                 closest_pair = (points[i], points[j]);
@@ -61,52 +186,122 @@ pub fn closest_pair_brute_force(points: &[Point]) -> Option<ClosestPairResult> {
 }
 
 /// Divide and conquer approach to find closest pair of points
-/// Time complexity: O(n log n)
-pub fn closest_pair_divide_conquer(points: &[Point]) -> Option<ClosestPairResult> {
+/// Time complexity: O(n log n); returns `None` for fewer than 2 points
+pub fn closest_pair_divide_conquer<P: PlanarPoint + Send>(points: &[P]) -> Option<ClosestPairResult<P>> {
+    divide_conquer_counting(points, &mut 0).map(with_euclidean_distance)
+}
+
+/// Divide and conquer closest pair, also returning the number of distance computations
+pub fn closest_pair_divide_conquer_counted<P: PlanarPoint + Send>(points: &[P]) -> Option<(ClosestPairResult<P>, u64)> {
+    let mut comparisons = 0;
+    divide_conquer_counting(points, &mut comparisons).map(|result| (with_euclidean_distance(result), comparisons))
+}
+
+/// Divide and conquer search; the returned `distance` is squared
+/// The x-sorted copy and one scratch buffer are the only allocations: the recursion
+/// borrows disjoint halves of both and merges each level back into y order
+fn divide_conquer_counting<P: PlanarPoint + Send>(points: &[P], comparisons: &mut u64) -> Option<ClosestPairResult<P>> {
     if points.len() < 2 {
         return None;
     }
     
-    // Create sorted copies
     let mut points_x = points.to_vec();
-    let mut points_y = points.to_vec();
+    points_x.sort_unstable_by(|a, b| a.x().partial_cmp(&b.x()).unwrap());
+    let mut scratch = points_x.clone();
     
-    // Sort by x and y coordinates
-    points_x.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
-    points_y.sort_by(|a, b| a.y.partial_cmp(&b.y).unwrap());
+    closest_pair_rec(&mut points_x, &mut scratch, comparisons)
+}
+
+/// Subproblems at least this large recurse on both halves in parallel
+const CLOSEST_PAIR_PARALLEL_MIN: usize = 4096;
+
+/// Closest pair of labeled points, returning both labels and the distance
+pub fn closest_pair_labeled<T>(points: &[LabeledPoint<T>]) -> Option<(&T, &T, f64)> {
+    let indexed: Vec<IndexedPoint> = points
+        .iter()
+        .enumerate()
+        .map(|(index, p)| IndexedPoint { point: p.point, index })
+        .collect();
     
-    closest_pair_rec(&points_x, &points_y)
+    closest_pair_divide_conquer(&indexed).map(|result| {
+        (
+            &points[result.point1.index].label,
+            &points[result.point2.index].label,
+            result.distance,
+        )
+    })
 }
 
-fn closest_pair_rec(points_x: &[Point], points_y: &[Point]) -> Option<ClosestPairResult> {
-    let n = points_x.len();
+/// Find every pair of points tied for the minimum distance (within a relative epsilon)
+/// The minimum comes from the divide and conquer search; an x-sorted sweep then
+/// collects all pairs within it. Duplicate-heavy input yields one result per duplicate pair,
+/// and fewer than 2 points yield an empty list
+pub fn closest_pairs_all<P: PlanarPoint + Send>(points: &[P]) -> Vec<ClosestPairResult<P>> {
+    let Some(closest) = closest_pair_divide_conquer(points) else {
+        return Vec::new();
+    };
+    let limit = closest.distance + 1e-9 * closest.distance.max(1.0);
+    
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x().partial_cmp(&b.x()).unwrap());
+    
+    let mut pairs = Vec::new();
+    for (i, a) in sorted.iter().enumerate() {
+        for b in sorted[i + 1..].iter().take_while(|b| b.x() - a.x() <= limit) {
+            let distance = a.planar_distance(b);
+            if distance <= limit {
+                pairs.push(ClosestPairResult {
+                    point1: *a,
+                    point2: *b,
+                    distance,
+                });
+            }
+        }
+    }
+    
+    pairs
+}
+
+/// Recursive step of the divide and conquer search; distances are squared throughout
+/// `points` arrives sorted by x and is left sorted by y; `scratch` is same-length workspace
+fn closest_pair_rec<P: PlanarPoint + Send>(points: &mut [P], scratch: &mut [P], comparisons: &mut u64) -> Option<ClosestPairResult<P>> {
+    let n = points.len();
     
     // Base case: use brute force for small arrays
     if n <= 3 {
-        return closest_pair_brute_force(points_x);
+        let result = brute_force_counting(points, comparisons);
+        points.sort_unstable_by(|a, b| a.y().partial_cmp(&b.y()).unwrap());
+        return result;
     }
     
     // Divide
     let mid = n / 2;
-    let midpoint = points_x[mid];
+    let midpoint = points[mid];
     
-    let (left_x, right_x) = points_x.split_at(mid);
-    
-    // Split points_y into left and right based on x coordinate
-    let mut left_y = Vec::new();
-    let mut right_y = Vec::new();
-    
-    for &point in points_y {
-        if point.x <= midpoint.x {
-            left_y.push(point);
+    // Conquer
+    let (left_result, right_result) = {
+        let (left, right) = points.split_at_mut(mid);
+        let (scratch_left, scratch_right) = scratch.split_at_mut(mid);
+        
+        if n >= CLOSEST_PAIR_PARALLEL_MIN {
+            let (mut left_comparisons, mut right_comparisons) = (0, 0);
+            let results = rayon::join(
+                || closest_pair_rec(left, scratch_left, &mut left_comparisons),
+                || closest_pair_rec(right, scratch_right, &mut right_comparisons),
+            );
+            *comparisons += left_comparisons + right_comparisons;
+            results
         } else {
-            right_y.push(point);
+            (
+                closest_pair_rec(left, scratch_left, comparisons),
+                closest_pair_rec(right, scratch_right, comparisons),
+            )
         }
-    }
+    };
     
-    // Conquer
-    let left_result = closest_pair_rec(left_x, &left_y);
-    let right_result = closest_pair_rec(right_x, &right_y);
+    // Both halves are now y-sorted; merge them so this level is too
+    merge_by_y(&points[..mid], &points[mid..], scratch);
+    points.copy_from_slice(scratch);
     
     // Find minimum distance from both sides
     let mut min_result = match (left_result, right_result) {
@@ -117,19 +312,24 @@ fn closest_pair_rec(points_x: &[Point], points_y: &[Point]) -> Option<ClosestPai
         (None, None) => return None,
     };
     
-    // Check points close to the dividing line
-    let mut strip = Vec::new();
-    for &point in points_y {
-        if (point.x - midpoint.x).abs() < min_result.distance {
-            strip.push(point);
+    // Check points close to the dividing line (distances here are squared),
+    // gathering them in y order into the now-free scratch buffer
+    let mut strip_len = 0;
+    for &point in points.iter() {
+        let dx = point.x() - midpoint.x();
+        if dx * dx < min_result.distance {
+            scratch[strip_len] = point;
+            strip_len += 1;
         }
     }
+    let strip = &scratch[..strip_len];
     
     // Check closest pair in strip
     for i in 0..strip.len() {
         let mut j = i + 1;
-        while j < strip.len() && (strip[j].y - strip[i].y) < min_result.distance {
-            let distance = strip[i].distance_to(&strip[j]);
+        while j < strip.len() && (strip[j].y() - strip[i].y()).powi(2) < min_result.distance {
+            let distance = strip[i].planar_distance_squared(&strip[j]);
+            *comparisons += 1;
             if distance < min_result.distance {
                 min_result = ClosestPairResult {
                     point1: strip[i],
@@ -144,36 +344,205 @@ fn closest_pair_rec(points_x: &[Point], points_y: &[Point]) -> Option<ClosestPai
     Some(min_result)
 }
 
+/// Merge two y-sorted runs into `dest`, which must hold exactly both
+fn merge_by_y<P: PlanarPoint>(left: &[P], right: &[P], dest: &mut [P]) {
+    let (mut i, mut j) = (0, 0);
+    
+    for slot in dest.iter_mut() {
+        if j >= right.len() || (i < left.len() && left[i].y() <= right[j].y()) {
+            *slot = left[i];
+            i += 1;
+        } else {
+            *slot = right[j];
+            j += 1;
+        }
+    }
+}
+
+/// Axis-aligned bounding box as (min corner, max corner), or `None` for empty input
+pub fn bounding_box(points: &[Point]) -> Option<(Point, Point)> {
+    let first = *points.first()?;
+    
+    Some(points.iter().skip(1).fold((first, first), |(min, max), p| {
+        (
+            Point::new(min.x.min(p.x), min.y.min(p.y)),
+            Point::new(max.x.max(p.x), max.y.max(p.y)),
+        )
+    }))
+}
+
+/// Arithmetic mean of the points, or `None` for empty input
+pub fn centroid(points: &[Point]) -> Option<Point> {
+    if points.is_empty() {
+        return None;
+    }
+    
+    let count = points.len() as f64;
+    let (sum_x, sum_y) = points
+        .iter()
+        .fold((0.0, 0.0), |(x, y), p| (x + p.x, y + p.y));
+    Some(Point::new(sum_x / count, sum_y / count))
+}
+
+/// Scale each axis independently onto [0, 1], returning the points with the min and max used
+/// An axis with no spread maps to 0; empty input returns the origin for both corners
+pub fn normalize_points(points: &[Point]) -> (Vec<Point>, Point, Point) {
+    let Some((min, max)) = bounding_box(points) else {
+        return (Vec::new(), Point::new(0.0, 0.0), Point::new(0.0, 0.0));
+    };
+    
+    let scale = |value: f64, low: f64, high: f64| {
+        if high > low { (value - low) / (high - low) } else { 0.0 }
+    };
+    let normalized = points
+        .iter()
+        .map(|p| Point::new(scale(p.x, min.x, max.x), scale(p.y, min.y, max.y)))
+        .collect();
+    
+    (normalized, min, max)
+}
+
+/// Inverse of `normalize_points`, mapping [0, 1] coordinates back onto `min..max`
+pub fn denormalize_points(points: &[Point], min: Point, max: Point) -> Vec<Point> {
+    points
+        .iter()
+        .map(|p| Point::new(min.x + p.x * (max.x - min.x), min.y + p.y * (max.y - min.y)))
+        .collect()
+}
+
+/// Sort points along the Morton (Z-order) curve so nearby points end up close in memory
+///
+/// Coordinates are quantized onto a `2^bits × 2^bits` grid spanning the bounding box
+/// of `points`: each axis maps `min..=max` linearly onto `0..=2^bits - 1` (an axis with
+/// no spread maps to 0). `bits` is clamped to `1..=32`; points sharing a grid cell keep
+/// their relative order
+pub fn morton_sort(points: &mut [Point], bits: u32) {
+    let Some((min, max)) = bounding_box(points) else {
+        return;
+    };
+    
+    let cells = ((1u64 << bits.clamp(1, 32)) - 1) as f64;
+    let quantize = |value: f64, low: f64, high: f64| {
+        if high > low { ((value - low) / (high - low) * cells).round() as u64 } else { 0 }
+    };
+    
+    points.sort_by_cached_key(|p| {
+        morton_code(quantize(p.x, min.x, max.x), quantize(p.y, min.y, max.y))
+    });
+}
+
+/// Interleave the low 32 bits of `x` and `y`, with `x` in the even bit positions
+fn morton_code(x: u64, y: u64) -> u64 {
+    spread_bits(x) | (spread_bits(y) << 1)
+}
+
+/// Move bit i of the low 32 bits to bit 2i
+fn spread_bits(value: u64) -> u64 {
+    let mut v = value & 0xFFFF_FFFF;
+    v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    (v | (v << 1)) & 0x5555_5555_5555_5555
+}
+
+/// Closest pair in any number of dimensions, using the first `dims` coordinates
+/// Sorts along the axis with the widest spread and sweeps, stopping each scan once
+/// the axis gap alone exceeds the best distance; O(n²) worst case, near O(n log n) on spread data
+/// Returns `None` for fewer than 2 points, `dims == 0`, a point with fewer than `dims`
+/// coordinates, or a NaN or infinite coordinate
+pub fn closest_pair_kd(points: &[Vec<f64>], dims: usize) -> Option<(Vec<f64>, Vec<f64>, f64)> {
+    if points.len() < 2 || dims == 0 {
+        return None;
+    }
+    if points.iter().any(|p| p.len() < dims || !p[..dims].iter().all(|c| c.is_finite())) {
+        return None;
+    }
+    
+    let spread = |axis: usize| {
+        let (min, max) = points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), p| {
+            (min.min(p[axis]), max.max(p[axis]))
+        });
+        max - min
+    };
+    let axis = (0..dims)
+        .max_by(|&a, &b| spread(a).total_cmp(&spread(b)))
+        .unwrap_or(0);
+    
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+    
+    let distance_squared = |a: &[f64], b: &[f64]| {
+        a[..dims].iter().zip(&b[..dims]).map(|(x, y)| (x - y) * (x - y)).sum::<f64>()
+    };
+    
+    let mut best = (order[0], order[1], distance_squared(&points[order[0]], &points[order[1]]));
+    for (i, &a) in order.iter().enumerate() {
+        for &b in &order[i + 1..] {
+            let gap = points[b][axis] - points[a][axis];
+            if gap * gap >= best.2 {
+                break;
+            }
+            let distance = distance_squared(&points[a], &points[b]);
+            if distance < best.2 {
+                best = (a, b, distance);
+            }
+        }
+    }
+    
+    Some((points[best.0].clone(), points[best.1].clone(), best.2.sqrt()))
+}
+
 /// Find the convex hull using Graham scan algorithm
 /// Time complexity: O(n log n)
+/// Duplicates are collapsed and only strict corners are kept: fewer than 3 unique
+/// points (including empty input) are returned as-is, and all-collinear input
+/// yields its two extreme points
 pub fn convex_hull_graham_scan(points: &[Point]) -> Vec<Point> {
-    if points.len() < 3 {
-        return points.to_vec();
-    }
+    // Unique points ordered bottom-most first (left-most in case of tie)
+    let mut unique = points.to_vec();
+    unique.sort_by(|a, b| {
+        a.y.partial_cmp(&b.y).unwrap().then(a.x.partial_cmp(&b.x).unwrap())
+    });
+    unique.dedup();
     
-    // Find the bottom-most point (and left-most in case of tie)
-    let mut bottom_point = points[0];
-    for &point in points.iter().skip(1) {
-        if point.y < bottom_point.y || (point.y == bottom_point.y && point.x < bottom_point.x) {
-            bottom_point = point;
-        }
+    if unique.len() < 3 {
+        return unique;
     }
     
-    // Sort points by polar angle with respect to bottom point
-    let mut sorted_points: Vec<Point> = points.iter()
-        .filter(|&&p| p != bottom_point)
-        .cloned()
-        .collect();
+    let bottom_point = unique[0];
     
+    // Sort by polar angle around the bottom point, nearer first on ties.
+    // Every point lies in the upper half-plane, so the cross product orders angles
+    let mut sorted_points = unique.split_off(1);
     sorted_points.sort_by(|a, b| {
-        let angle_a = polar_angle(&bottom_point, a);
-        let angle_b = polar_angle(&bottom_point, b);
-        angle_a.partial_cmp(&angle_b).unwrap()
+        let turn = cross_product(&bottom_point, a, b);
+        if turn > 0.0 {
+            std::cmp::Ordering::Less
+        } else if turn < 0.0 {
+            std::cmp::Ordering::Greater
+        } else {
+            bottom_point
+                .distance_squared_to(a)
+                .partial_cmp(&bottom_point.distance_squared_to(b))
+                .unwrap()
+        }
     });
     
+    // Points sharing an angle lie on the segment to the farthest one; keep only that
+    let mut farthest_per_angle: Vec<Point> = Vec::with_capacity(sorted_points.len());
+    for point in sorted_points {
+        if let Some(last) = farthest_per_angle.last() {
+            if cross_product(&bottom_point, last, &point) == 0.0 {
+                farthest_per_angle.pop();
+            }
+        }
+        farthest_per_angle.push(point);
+    }
+    
     let mut hull = vec![bottom_point];
     
-    for point in sorted_points {
+    for point in farthest_per_angle {
         // Remove points that make clockwise turn
         while hull.len() > 1 && cross_product(&hull[hull.len()-2], &hull[hull.len()-1], &point) <= 0.0 {
             hull.pop();
@@ -184,14 +553,135 @@ pub fn convex_hull_graham_scan(points: &[Point]) -> Vec<Point> {
     hull
 }
 
-fn polar_angle(origin: &Point, point: &Point) -> f64 {
-    (point.y - origin.y).atan2(point.x - origin.x)
+/// Find the convex hull using the divide-and-conquer Quickhull algorithm
+/// Expected O(n log n), worst case O(n²); returns strict corners counter-clockwise
+/// from the left-most point, with the same degenerate handling as Graham scan
+pub fn convex_hull_quickhull(points: &[Point]) -> Vec<Point> {
+    let mut unique = points.to_vec();
+    unique.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x).unwrap().then(a.y.partial_cmp(&b.y).unwrap())
+    });
+    unique.dedup();
+    
+    if unique.len() < 3 {
+        return unique;
+    }
+    
+    let left = unique[0];
+    let right = unique[unique.len() - 1];
+    let below: Vec<Point> = unique.iter().copied().filter(|p| cross_product(&left, &right, p) < 0.0).collect();
+    let above: Vec<Point> = unique.iter().copied().filter(|p| cross_product(&right, &left, p) < 0.0).collect();
+    
+    let mut hull = vec![left];
+    quickhull_side(&left, &right, &below, &mut hull);
+    hull.push(right);
+    quickhull_side(&right, &left, &above, &mut hull);
+    hull
+}
+
+/// Append, in order, the hull vertices strictly right of the directed edge a -> b
+fn quickhull_side(a: &Point, b: &Point, candidates: &[Point], hull: &mut Vec<Point>) {
+    let Some(&farthest) = candidates.iter().max_by(|p, q| {
+        cross_product(b, a, p).partial_cmp(&cross_product(b, a, q)).unwrap()
+    }) else {
+        return;
+    };
+    
+    let before: Vec<Point> = candidates.iter().copied().filter(|p| cross_product(a, &farthest, p) < 0.0).collect();
+    let after: Vec<Point> = candidates.iter().copied().filter(|p| cross_product(&farthest, b, p) < 0.0).collect();
+    
+    quickhull_side(a, &farthest, &before, hull);
+    hull.push(farthest);
+    quickhull_side(&farthest, b, &after, hull);
+}
+
+/// Find the farthest pair of points (the diameter of the set)
+/// Uses rotating calipers on the convex hull: O(n log n); `None` for fewer than 2 points
+pub fn farthest_pair(points: &[Point]) -> Option<(Point, Point, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    
+    let hull = convex_hull_graham_scan(points);
+    if hull.len() < 3 {
+        // Collinear input: the extremes along the line are the farthest pair
+        let by_position = |a: &&Point, b: &&Point| {
+            a.x.partial_cmp(&b.x).unwrap().then(a.y.partial_cmp(&b.y).unwrap())
+        };
+        let first = *points.iter().min_by(by_position)?;
+        let last = *points.iter().max_by(by_position)?;
+        return Some((first, last, first.distance_to(&last)));
+    }
+    
+    let n = hull.len();
+    let mut best = (hull[0], hull[1], hull[0].distance_squared_to(&hull[1]));
+    let mut j = 1;
+    
+    for i in 0..n {
+        let next = (i + 1) % n;
+        
+        // Advance the opposite caliper while it moves away from edge (i, next)
+        while cross_product(&hull[i], &hull[next], &hull[(j + 1) % n]).abs()
+            > cross_product(&hull[i], &hull[next], &hull[j]).abs()
+        {
+            j = (j + 1) % n;
+        }
+        
+        for candidate in [hull[i], hull[next]] {
+            let distance = candidate.distance_squared_to(&hull[j]);
+            if distance > best.2 {
+                best = (candidate, hull[j], distance);
+            }
+        }
+    }
+    
+    Some((best.0, best.1, best.2.sqrt()))
 }
 
 fn cross_product(o: &Point, a: &Point, b: &Point) -> f64 {
     (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
 }
 
+/// Write the points as a GeoJSON FeatureCollection of Point features
+pub fn export_geojson(points: &[Point], path: &str) -> std::io::Result<()> {
+    write_geojson(points, path, false)
+}
+
+/// Like `export_geojson`, plus the convex hull as a Polygon feature when there are
+/// at least three non-collinear points
+pub fn export_geojson_with_hull(points: &[Point], path: &str) -> std::io::Result<()> {
+    write_geojson(points, path, true)
+}
+
+fn write_geojson(points: &[Point], path: &str, include_hull: bool) -> std::io::Result<()> {
+    let mut features: Vec<serde_json::Value> = points
+        .iter()
+        .map(|p| {
+            serde_json::json!({
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [p.x, p.y] },
+                "properties": {}
+            })
+        })
+        .collect();
+    
+    if include_hull {
+        let hull = convex_hull_graham_scan(points);
+        if hull.len() >= 3 {
+            // GeoJSON rings are closed by repeating the first position
+            let ring: Vec<[f64; 2]> = hull.iter().chain(hull.first()).map(|p| [p.x, p.y]).collect();
+            features.push(serde_json::json!({
+                "type": "Feature",
+                "geometry": { "type": "Polygon", "coordinates": [ring] },
+                "properties": { "name": "convex hull" }
+            }));
+        }
+    }
+    
+    let collection = serde_json::json!({ "type": "FeatureCollection", "features": features });
+    std::fs::write(path, serde_json::to_string_pretty(&collection)?)
+}
+
 /// Line segment intersection using divide and conquer
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct LineSegment {
@@ -238,6 +728,7 @@ fn on_segment(pi: &Point, pj: &Point, pk: &Point) -> bool {
 }
 
 /// Find all intersecting pairs of line segments using divide and conquer
+/// Fewer than two segments yield no pairs
 pub fn find_intersecting_segments(segments: &[LineSegment]) -> Vec<(usize, usize)> {
     let mut intersections = Vec::new();
     
@@ -253,8 +744,54 @@ pub fn find_intersecting_segments(segments: &[LineSegment]) -> Vec<(usize, usize
     intersections
 }
 
+/// Parallel version of `find_intersecting_segments`: the outer loop is split across
+/// Rayon workers and the pairs are returned sorted, so output matches the sequential order
+pub fn find_intersecting_segments_parallel(segments: &[LineSegment]) -> Vec<(usize, usize)> {
+    let mut intersections: Vec<(usize, usize)> = (0..segments.len())
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            ((i + 1)..segments.len())
+                .filter(move |&j| segments[i].intersects(&segments[j]))
+                .map(move |j| (i, j))
+        })
+        .collect();
+    
+    intersections.sort_unstable();
+    intersections
+}
+
+/// Count intersecting pairs with the brute-force check, splitting the outer loop across threads
+pub fn count_intersecting_segments(segments: &[LineSegment]) -> usize {
+    let indices: Vec<usize> = (0..segments.len()).collect();
+    
+    parallel::map_reduce(
+        &indices,
+        |&i| {
+            segments[i + 1..]
+                .iter()
+                .filter(|other| segments[i].intersects(other))
+                .count()
+        },
+        |a, b| a + b,
+        0,
+    )
+}
+
+/// Extra levels beyond a perfectly balanced tree still reported as balanced
+const KD_BALANCE_SLACK: usize = 2;
+
+/// Shape summary of a `KdTree`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdTreeStats {
+    pub node_count: usize,
+    /// Number of levels; 0 for an empty tree
+    pub max_depth: usize,
+    /// Whether `max_depth` is within `KD_BALANCE_SLACK` levels of log2(n) + 1
+    pub is_balanced: bool,
+}
+
 /// K-d tree implementation for efficient nearest neighbor search
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct KdTree {
     root: Option<Box<KdNode>>,
 }
@@ -272,7 +809,7 @@ impl KdTree {
         Self { root: None }
     }
     
-    /// Build k-d tree from points
+    /// Build k-d tree from points; empty input yields an empty tree whose queries return `None`
     pub fn build(points: &[Point]) -> Self {
         let mut tree = Self::new();
         if !points.is_empty() {
@@ -312,56 +849,118 @@ impl KdTree {
         node
     }
     
-    /// Find nearest neighbor to a query point
-    pub fn nearest_neighbor(&self, query: &Point) -> Option<Point> {
-        self.root.as_ref().map(|root| {
-            let mut best = root.point;
-            let mut best_distance = query.distance_squared_to(&best);
-            
-            Self::nearest_neighbor_recursive(root, query, &mut best, &mut best_distance);
-            best
-        })
+    /// Insert a point, descending by alternating dimensions to a free leaf slot
+    /// Inserts do not rebalance, so long insert streams can degrade queries
+    /// toward O(n); call `build` again periodically to restore balance
+    pub fn insert(&mut self, point: Point) {
+        let mut slot = &mut self.root;
+        let mut dimension = 0;
+        
+        while let Some(node) = slot {
+            let (coord, node_coord) = if node.dimension == 0 {
+                (point.x, node.point.x)
+            } else {
+                (point.y, node.point.y)
+            };
+            dimension = (node.dimension + 1) % 2;
+            slot = if coord < node_coord { &mut node.left } else { &mut node.right };
+        }
+        
+        *slot = Some(Box::new(KdNode {
+            point,
+            left: None,
+            right: None,
+            dimension,
+        }));
     }
     
-    fn nearest_neighbor_recursive(
-        node: &KdNode,
-        query: &Point,
-        best: &mut Point,
-        best_distance: &mut f64,
-    ) {
-        let distance = query.distance_squared_to(&node.point);
-        if distance < *best_distance {
-            *best = node.point;
-            *best_distance = distance;
-        }
+    /// Node count and depth of the tree, for deciding whether to rebuild after inserts
+    pub fn stats(&self) -> KdTreeStats {
+        // Iterative walk: insert-degraded trees can be too deep to recurse through
+        let mut node_count: usize = 0;
+        let mut max_depth = 0;
+        let mut stack: Vec<(&KdNode, usize)> = self.root.iter().map(|root| (root.as_ref(), 1)).collect();
         
-        let query_coord = if node.dimension == 0 { query.x } else { query.y };
-        let node_coord = if node.dimension == 0 { node.point.x } else { node.point.y };
+        while let Some((node, depth)) = stack.pop() {
+            node_count += 1;
+            max_depth = max_depth.max(depth);
+            for child in [&node.left, &node.right].into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
+        }
         
-        let (near_child, far_child) = if query_coord < node_coord {
-            (&node.left, &node.right)
-        } else {
-            (&node.right, &node.left)
-        };
+        // A median-split build has exactly floor(log2(n)) + 1 levels
+        let balanced_depth = if node_count == 0 { 0 } else { node_count.ilog2() as usize + 1 };
         
-        // Search near child first
-        if let Some(child) = near_child {
-            Self::nearest_neighbor_recursive(child, query, best, best_distance);
+        KdTreeStats {
+            node_count,
+            max_depth,
+            is_balanced: max_depth <= balanced_depth + KD_BALANCE_SLACK,
         }
+    }
+    
+    /// Find nearest neighbor to a query point
+    pub fn nearest_neighbor(&self, query: &Point) -> Option<Point> {
+        self.nearest_neighbor_with_distance(query).map(|(point, _)| point)
+    }
+    
+    /// Nearest neighbor of every query, computed in parallel; results follow query order
+    /// An empty tree has no neighbors, so it returns an empty `Vec`
+    pub fn nearest_neighbors_batch(&self, queries: &[Point]) -> Vec<Point> {
+        queries
+            .par_iter()
+            .filter_map(|query| self.nearest_neighbor(query))
+            .collect()
+    }
+    
+    /// Nearest point together with its Euclidean distance to `query`
+    /// Walks the tree with an explicit stack, so insert-degraded trees cannot overflow it
+    pub fn nearest_neighbor_with_distance(&self, query: &Point) -> Option<(Point, f64)> {
+        let root = self.root.as_ref()?;
+        let mut best = root.point;
+        let mut best_distance = query.distance_squared_to(&best);
+        
+        // Each entry carries the squared distance from the query to its splitting plane,
+        // so far subtrees queued earlier are pruned against the best found since
+        let mut stack: Vec<(&KdNode, f64)> = vec![(root, 0.0)];
         
-        // Check if we need to search far child
-        let axis_distance = (query_coord - node_coord).powi(2);
-        if axis_distance < *best_distance {
+        while let Some((node, plane_distance)) = stack.pop() {
+            if plane_distance >= best_distance {
+                continue;
+            }
+            
+            let distance = query.distance_squared_to(&node.point);
+            if distance < best_distance {
+                best = node.point;
+                best_distance = distance;
+            }
+            
+            let query_coord = if node.dimension == 0 { query.x } else { query.y };
+            let node_coord = if node.dimension == 0 { node.point.x } else { node.point.y };
+            
+            let (near_child, far_child) = if query_coord < node_coord {
+                (&node.left, &node.right)
+            } else {
+                (&node.right, &node.left)
+            };
+            
+            // Push the far child first so the near child is searched first
             if let Some(child) = far_child {
-                Self::nearest_neighbor_recursive(child, query, best, best_distance);
+                stack.push((child, (query_coord - node_coord).powi(2)));
+            }
+            if let Some(child) = near_child {
+                stack.push((child, 0.0));
             }
         }
+        
+        Some((best, best_distance.sqrt()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data_generator::DataGenerator;
     
     #[test]
     fn test_point_distance() {
@@ -396,6 +995,324 @@ mod tests {
         assert!((result.distance - 2.0_f64.sqrt()).abs() < 1e-10);
     }
     
+    #[test]
+    fn test_closest_pairs_all_ties() {
+        // Two pairs at distance 1, everything else farther apart
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(10.0, 11.0),
+            Point::new(5.0, 5.0),
+        ];
+        
+        let pairs = closest_pairs_all(&points);
+        assert_eq!(pairs.len(), 2);
+        for pair in &pairs {
+            assert!((pair.distance - 1.0).abs() < 1e-12);
+        }
+        assert!(pairs.iter().any(|p| p.point1.x == 0.0 || p.point2.x == 0.0));
+        assert!(pairs.iter().any(|p| p.point1.x == 10.0 && p.point2.x == 10.0));
+    }
+    
+    #[test]
+    fn test_closest_pairs_all_duplicates() {
+        let points = vec![Point::new(2.0, 3.0); 4];
+        
+        let pairs = closest_pairs_all(&points);
+        assert_eq!(pairs.len(), 6);
+        assert!(pairs.iter().all(|p| p.distance == 0.0));
+        assert!(closest_pairs_all(&points[..1]).is_empty());
+    }
+    
+    #[test]
+    fn test_closest_pair_kd_3d_matches_brute_force() {
+        let points: Vec<Vec<f64>> = DataGenerator::with_seed(13, || {
+            DataGenerator::generate_random_points(600)
+                .chunks(2)
+                .map(|pair| vec![pair[0].x, pair[0].y, pair[1].x])
+                .collect()
+        });
+        
+        let distance = |a: &[f64], b: &[f64]| {
+            a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt()
+        };
+        let expected = (0..points.len())
+            .flat_map(|i| ((i + 1)..points.len()).map(move |j| (i, j)))
+            .map(|(i, j)| distance(&points[i], &points[j]))
+            .fold(f64::INFINITY, f64::min);
+        
+        let (a, b, actual) = closest_pair_kd(&points, 3).unwrap();
+        assert!((actual - expected).abs() < 1e-12);
+        assert!((distance(&a, &b) - actual).abs() < 1e-12);
+        assert!(closest_pair_kd(&points[..1], 3).is_none());
+    }
+    
+    #[test]
+    fn test_closest_pair_kd_rejects_invalid_points() {
+        let valid = vec![vec![0.0, 0.0, 0.0], vec![1.0, 1.0, 1.0]];
+        assert!(closest_pair_kd(&valid, 3).is_some());
+        assert!(closest_pair_kd(&valid, 0).is_none());
+        
+        // Too few coordinates for the requested dimensions
+        assert!(closest_pair_kd(&valid, 4).is_none());
+        let ragged = vec![vec![0.0, 0.0, 0.0], vec![1.0, 1.0]];
+        assert!(closest_pair_kd(&ragged, 3).is_none());
+        assert!(closest_pair_kd(&ragged, 2).is_some());
+        
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let points = vec![vec![0.0, 0.0], vec![1.0, bad], vec![2.0, 2.0]];
+            assert!(closest_pair_kd(&points, 2).is_none());
+            // Coordinates past `dims` are ignored
+            assert!(closest_pair_kd(&points, 1).is_some());
+        }
+    }
+    
+    #[test]
+    fn test_closest_pair_labeled() {
+        let points = vec![
+            LabeledPoint::new(Point::new(0.0, 0.0), 101),
+            LabeledPoint::new(Point::new(9.0, 9.0), 102),
+            LabeledPoint::new(Point::new(4.0, 4.0), 103),
+            LabeledPoint::new(Point::new(4.5, 4.0), 104),
+            LabeledPoint::new(Point::new(-3.0, 7.0), 105),
+        ];
+        
+        let (a, b, distance) = closest_pair_labeled(&points).unwrap();
+        let mut ids = [*a, *b];
+        ids.sort();
+        assert_eq!(ids, [103, 104]);
+        assert!((distance - 0.5).abs() < 1e-12);
+        
+        let named = vec![LabeledPoint::new(Point::new(1.0, 1.0), "only".to_string())];
+        assert!(closest_pair_labeled(&named).is_none());
+    }
+    
+    #[test]
+    fn test_closest_pair_comparison_counts() {
+        let n = 4000u64;
+        let points = DataGenerator::with_seed(3, || DataGenerator::generate_random_points(n as usize));
+        
+        let (brute, brute_count) = closest_pair_brute_force_counted(&points).unwrap();
+        let (dc, dc_count) = closest_pair_divide_conquer_counted(&points).unwrap();
+        
+        assert_eq!(brute_count, n * (n - 1) / 2);
+        assert!(dc_count * 50 < n * n, "{} comparisons for n = {}", dc_count, n);
+        assert_eq!(brute.distance, dc.distance);
+    }
+    
+    #[test]
+    fn test_closest_pair_squared_distances_match_reference() {
+        let points = DataGenerator::with_seed(21, || DataGenerator::generate_random_points(1500));
+        
+        // Reference: minimum of the true Euclidean distances over all pairs
+        let expected = (0..points.len())
+            .flat_map(|i| ((i + 1)..points.len()).map(move |j| (i, j)))
+            .map(|(i, j)| points[i].distance_to(&points[j]))
+            .fold(f64::INFINITY, f64::min);
+        
+        let brute = closest_pair_brute_force(&points).unwrap();
+        let dc = closest_pair_divide_conquer(&points).unwrap();
+        assert_eq!(brute.distance, expected);
+        assert_eq!(dc.distance, expected);
+        assert_eq!(dc.distance, dc.point1.distance_to(&dc.point2));
+    }
+    
+    #[test]
+    fn test_point32_closest_pair_matches_f64() {
+        let points = DataGenerator::with_seed(7, || DataGenerator::generate_random_points(2000));
+        let points32: Vec<Point32> = points.iter().map(|&p| Point32::from(p)).collect();
+        
+        let result64 = closest_pair_divide_conquer(&points).unwrap();
+        let result32 = closest_pair_divide_conquer(&points32).unwrap();
+        
+        assert!((result64.distance - result32.distance).abs() < 1e-3);
+        assert!(
+            (result32.point1.distance_to(&result32.point2) as f64 - result32.distance).abs() < 1e-3
+        );
+    }
+    
+    #[test]
+    fn test_bounding_box_and_centroid() {
+        let points = DataGenerator::generate_grid_points(5);
+        
+        let (min, max) = bounding_box(&points).unwrap();
+        assert_eq!(min, Point::new(0.0, 0.0));
+        assert_eq!(max, Point::new(4.0, 4.0));
+        assert_eq!(centroid(&points).unwrap(), Point::new(2.0, 2.0));
+        
+        assert!(bounding_box(&[]).is_none());
+        assert!(centroid(&[]).is_none());
+    }
+    
+    #[test]
+    fn test_normalize_points_round_trip() {
+        let points = DataGenerator::generate_grid_points(7);
+        
+        let (normalized, min, max) = normalize_points(&points);
+        assert_eq!((min, max), (Point::new(0.0, 0.0), Point::new(6.0, 6.0)));
+        assert!(normalized
+            .iter()
+            .all(|p| (0.0..=1.0).contains(&p.x) && (0.0..=1.0).contains(&p.y)));
+        
+        let restored = denormalize_points(&normalized, min, max);
+        for (original, back) in points.iter().zip(&restored) {
+            assert!(original.distance_to(back) < 1e-12);
+        }
+        
+        let (flat, _, _) = normalize_points(&[Point::new(3.0, 1.0), Point::new(5.0, 1.0)]);
+        assert_eq!(flat, vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0)]);
+        assert!(normalize_points(&[]).0.is_empty());
+    }
+    
+    #[test]
+    fn test_export_geojson() {
+        let points = DataGenerator::generate_grid_points(4);
+        let path = std::env::temp_dir().join("dcp_test_points.geojson");
+        let path = path.to_string_lossy();
+        
+        export_geojson(&points, &path).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path.as_ref()).unwrap()).unwrap();
+        assert_eq!(value["type"], "FeatureCollection");
+        assert_eq!(value["features"].as_array().unwrap().len(), 16);
+        
+        export_geojson_with_hull(&points, &path).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path.as_ref()).unwrap()).unwrap();
+        let features = value["features"].as_array().unwrap();
+        assert_eq!(features.len(), 17);
+        let ring = features[16]["geometry"]["coordinates"][0].as_array().unwrap();
+        assert_eq!(ring.first(), ring.last());
+        
+        let _ = std::fs::remove_file(path.as_ref());
+    }
+    
+    #[test]
+    fn test_morton_sort_keeps_neighbors_close() {
+        use rand::seq::SliceRandom;
+        
+        // Mean index distance between each grid point and its right and upper neighbors
+        fn mean_neighbor_gap(points: &[Point]) -> f64 {
+            let index: std::collections::HashMap<(i64, i64), usize> = points
+                .iter()
+                .enumerate()
+                .map(|(i, p)| ((p.x as i64, p.y as i64), i))
+                .collect();
+            let gaps: Vec<usize> = index
+                .iter()
+                .flat_map(|(&(x, y), &i)| {
+                    [(x + 1, y), (x, y + 1)]
+                        .into_iter()
+                        .filter_map(|cell| index.get(&cell))
+                        .map(move |&j| i.abs_diff(j))
+                })
+                .collect();
+            gaps.iter().sum::<usize>() as f64 / gaps.len() as f64
+        }
+        
+        let mut points = DataGenerator::generate_grid_points(32);
+        points.shuffle(&mut rand::rng());
+        let shuffled_gap = mean_neighbor_gap(&points);
+        
+        morton_sort(&mut points, 5);
+        assert_eq!(
+            points[..4],
+            [Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0), Point::new(1.0, 1.0)]
+        );
+        let morton_gap = mean_neighbor_gap(&points);
+        assert!(morton_gap < 20.0, "mean neighbor gap {}", morton_gap);
+        assert!(morton_gap * 10.0 < shuffled_gap);
+    }
+    
+    #[test]
+    fn test_convex_hull_duplicates() {
+        let square = [
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let mut points: Vec<Point> = square.iter().flat_map(|&p| [p, p, p]).collect();
+        points.push(Point::new(1.0, 1.0));
+        points.push(Point::new(1.0, 0.0));
+        
+        let hull = convex_hull_graham_scan(&points);
+        assert_eq!(hull.len(), 4);
+        for corner in &square {
+            assert!(hull.contains(corner));
+        }
+    }
+    
+    #[test]
+    fn test_convex_hull_fewer_than_three_unique() {
+        let a = Point::new(1.0, 1.0);
+        let b = Point::new(3.0, -1.0);
+        
+        assert!(convex_hull_graham_scan(&[]).is_empty());
+        assert_eq!(convex_hull_graham_scan(&[a, a, a]), vec![a]);
+        assert_eq!(convex_hull_graham_scan(&[a, b, a, b]), vec![b, a]);
+    }
+    
+    #[test]
+    fn test_convex_hull_collinear() {
+        let points: Vec<Point> = [3, 0, 5, 1, 4, 2, 5]
+            .iter()
+            .map(|&i| Point::new(i as f64, 2.0 * i as f64))
+            .collect();
+        
+        let hull = convex_hull_graham_scan(&points);
+        assert_eq!(hull, vec![Point::new(0.0, 0.0), Point::new(5.0, 10.0)]);
+    }
+    
+    #[test]
+    fn test_quickhull_matches_graham_scan() {
+        let sorted = |mut hull: Vec<Point>| {
+            hull.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap().then(a.y.partial_cmp(&b.y).unwrap()));
+            hull
+        };
+        
+        for seed in 0..5 {
+            let points = DataGenerator::with_seed(seed, || DataGenerator::generate_random_points(1000));
+            let quickhull = convex_hull_quickhull(&points);
+            let graham = convex_hull_graham_scan(&points);
+            
+            assert!(quickhull.len() >= 3);
+            assert_eq!(sorted(quickhull), sorted(graham));
+        }
+        
+        let grid = DataGenerator::generate_grid_points(6);
+        assert_eq!(sorted(convex_hull_quickhull(&grid)), sorted(convex_hull_graham_scan(&grid)));
+        assert_eq!(convex_hull_quickhull(&grid).len(), 4);
+    }
+    
+    #[test]
+    fn test_farthest_pair_on_circle() {
+        let radius = 50.0;
+        let points = DataGenerator::generate_circular_points(360, radius);
+        
+        let (a, b, distance) = farthest_pair(&points).unwrap();
+        assert!((distance - 2.0 * radius).abs() < 1e-9);
+        assert!((a.distance_to(&b) - distance).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_farthest_pair_matches_brute_force() {
+        let points = DataGenerator::with_seed(11, || DataGenerator::generate_random_points(500));
+        let expected = points
+            .iter()
+            .flat_map(|a| points.iter().map(move |b| a.distance_to(b)))
+            .fold(0.0, f64::max);
+        
+        let (_, _, distance) = farthest_pair(&points).unwrap();
+        assert!((distance - expected).abs() < 1e-9);
+        
+        let line: Vec<Point> = (0..10).map(|i| Point::new(i as f64, 2.0 * i as f64)).collect();
+        let (_, _, distance) = farthest_pair(&line).unwrap();
+        assert!((distance - line[0].distance_to(&line[9])).abs() < 1e-12);
+        assert!(farthest_pair(&line[..1]).is_none());
+    }
+    
     #[test]
     fn test_line_segment_intersection() {
         let seg1 = LineSegment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
@@ -424,5 +1341,165 @@ mod tests {
         
         // Should find one of the nearby points
         assert!(query.distance_to(&nearest) < 3.0);
+        
+        let (point, distance) = tree.nearest_neighbor_with_distance(&query).unwrap();
+        assert_eq!(point, Point::new(5.0, 4.0));
+        assert!((distance - query.distance_to(&point)).abs() < 1e-12);
+        assert!(KdTree::new().nearest_neighbor_with_distance(&query).is_none());
+    }
+    
+    #[test]
+    fn test_empty_and_single_point_inputs() {
+        let empty: Vec<Point> = Vec::new();
+        let single = vec![Point::new(1.0, 2.0)];
+        
+        assert!(convex_hull_graham_scan(&empty).is_empty());
+        assert_eq!(convex_hull_graham_scan(&single), single);
+        assert!(convex_hull_quickhull(&empty).is_empty());
+        
+        assert!(closest_pair_divide_conquer(&empty).is_none());
+        assert!(closest_pair_divide_conquer(&single).is_none());
+        assert!(closest_pairs_all(&single).is_empty());
+        assert!(farthest_pair(&single).is_none());
+        assert!(closest_pair_kd(&[], 2).is_none());
+        assert!(closest_pair_kd(&[vec![1.0, 2.0]], 2).is_none());
+        assert!(bounding_box(&empty).is_none());
+        assert!(centroid(&empty).is_none());
+        
+        let query = Point::new(0.0, 0.0);
+        assert!(KdTree::build(&empty).nearest_neighbor(&query).is_none());
+        assert_eq!(KdTree::build(&single).nearest_neighbor(&query), Some(single[0]));
+        
+        let segment = LineSegment::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0));
+        assert!(find_intersecting_segments(&[]).is_empty());
+        assert!(find_intersecting_segments(&[segment]).is_empty());
+        assert!(find_intersecting_segments_parallel(&[segment]).is_empty());
+        assert_eq!(count_intersecting_segments(&[segment]), 0);
+    }
+    
+    #[test]
+    fn test_closest_pair_presplit_matches_brute_force() {
+        let mut inputs = vec![
+            DataGenerator::generate_random_points(5000),
+            DataGenerator::generate_grid_points(40),
+            DataGenerator::generate_clustered_points(4, 300, 2.0),
+        ];
+        // Many points sharing the split x coordinate
+        inputs.push((0..500).map(|i| Point::new((i % 3) as f64, i as f64 * 0.37)).collect());
+        
+        for points in &inputs {
+            let expected = closest_pair_brute_force(points).unwrap();
+            let actual = closest_pair_divide_conquer(points).unwrap();
+            assert_eq!(actual.distance, expected.distance);
+            assert_eq!(actual.point1.distance_to(&actual.point2), actual.distance);
+        }
+    }
+    
+    #[test]
+    fn test_kdtree_stats() {
+        let points = DataGenerator::generate_random_points(1000);
+        let stats = KdTree::build(&points).stats();
+        assert_eq!(stats.node_count, 1000);
+        assert_eq!(stats.max_depth, 10);
+        assert!(stats.is_balanced);
+        
+        // Sorted inserts degenerate into a chain
+        let mut chain = KdTree::new();
+        for i in 0..100 {
+            chain.insert(Point::new(i as f64, i as f64));
+        }
+        let stats = chain.stats();
+        assert_eq!(stats.node_count, 100);
+        assert_eq!(stats.max_depth, 100);
+        assert!(!stats.is_balanced);
+        
+        assert_eq!(
+            KdTree::new().stats(),
+            KdTreeStats { node_count: 0, max_depth: 0, is_balanced: true }
+        );
+    }
+    
+    #[test]
+    fn test_kdtree_insert_matches_build() {
+        let (points, queries) = DataGenerator::with_seed(5, || {
+            (
+                DataGenerator::generate_random_points(1000),
+                DataGenerator::generate_random_points(200),
+            )
+        });
+        
+        let built = KdTree::build(&points);
+        let mut inserted = KdTree::new();
+        for &point in &points {
+            inserted.insert(point);
+        }
+        
+        for query in &queries {
+            let expected = built.nearest_neighbor(query).unwrap();
+            let actual = inserted.nearest_neighbor(query).unwrap();
+            assert_eq!(query.distance_to(&actual), query.distance_to(&expected));
+        }
+    }
+    
+    #[test]
+    fn test_kdtree_nearest_neighbor_on_unbalanced_tree() {
+        let (mut points, queries) = DataGenerator::with_seed(11, || {
+            (
+                DataGenerator::generate_random_points(3000),
+                DataGenerator::generate_random_points(100),
+            )
+        });
+        points.sort_by(|a, b| a.x.total_cmp(&b.x));
+        
+        let mut tree = KdTree::new();
+        for &point in &points {
+            tree.insert(point);
+        }
+        assert!(!tree.stats().is_balanced);
+        
+        for query in &queries {
+            let (_, distance) = tree.nearest_neighbor_with_distance(query).unwrap();
+            let expected = points
+                .iter()
+                .map(|p| query.distance_to(p))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(distance, expected);
+        }
+    }
+    
+    #[test]
+    fn test_nearest_neighbors_batch_matches_single_queries() {
+        let (points, queries) = DataGenerator::with_seed(17, || {
+            (
+                DataGenerator::generate_random_points(5000),
+                DataGenerator::generate_random_points(2000),
+            )
+        });
+        let tree = KdTree::build(&points);
+        
+        let batch = tree.nearest_neighbors_batch(&queries);
+        let single: Vec<Point> = queries
+            .iter()
+            .map(|query| tree.nearest_neighbor(query).unwrap())
+            .collect();
+        assert_eq!(batch, single);
+        
+        assert!(KdTree::new().nearest_neighbors_batch(&queries).is_empty());
+    }
+    
+    #[test]
+    fn test_find_intersecting_segments_parallel_matches_sequential() {
+        let points = DataGenerator::generate_random_points(1000);
+        let segments: Vec<LineSegment> = points
+            .chunks(2)
+            .map(|pair| LineSegment::new(pair[0], pair[1]))
+            .collect();
+        
+        let sequential = find_intersecting_segments(&segments);
+        let parallel = find_intersecting_segments_parallel(&segments);
+        
+        assert!(!sequential.is_empty());
+        assert_eq!(parallel, sequential);
+        assert_eq!(count_intersecting_segments(&segments), sequential.len());
     }
 }
\ No newline at end of file
@@ -0,0 +1,16 @@
+//! Divide and conquer sorting, matrix and geometry algorithms with a benchmark harness
+//!
+//! The `divide-conquer-processor` binary is a CLI over these modules
+
+pub mod benchmark;
+pub mod data_generator;
+pub mod dc;
+pub mod geometry;
+pub mod logging;
+pub mod matrix;
+pub mod parallel;
+pub mod sequence;
+pub mod sorting;
+pub mod streaming;
+pub mod verification;
+pub mod visualization;
@@ -0,0 +1,135 @@
+use indicatif::ProgressBar;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+/// How much status output the CLI prints
+/// Final results and errors are always printed regardless of level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Quiet = 0,
+    Normal = 1,
+    Verbose = 2,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Normal as u8);
+
+/// Progress bar currently on screen, which printed lines must not draw over
+static PROGRESS_BAR: Mutex<Option<ProgressBar>> = Mutex::new(None);
+
+/// Set the process-wide output level
+pub fn set_level(level: LogLevel) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Current process-wide output level
+pub fn level() -> LogLevel {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => LogLevel::Quiet,
+        1 => LogLevel::Normal,
+        _ => LogLevel::Verbose,
+    }
+}
+
+/// Whether messages at `level` should be printed
+pub fn enabled(level: LogLevel) -> bool {
+    level <= self::level()
+}
+
+/// Turn off ANSI colors for `--no-color` or a non-empty `NO_COLOR` environment variable
+pub fn configure_color(no_color: bool) {
+    let env_disabled = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    if no_color || env_disabled {
+        colored::control::set_override(false);
+    }
+}
+
+/// Register the progress bar on screen, or `None` once it is cleared
+pub fn set_progress_bar(bar: Option<ProgressBar>) {
+    *PROGRESS_BAR.lock().unwrap() = bar;
+}
+
+/// Run `print`, hiding the registered progress bar while it writes
+fn suspend_progress(print: impl FnOnce()) {
+    match &*PROGRESS_BAR.lock().unwrap() {
+        Some(bar) => bar.suspend(print),
+        None => print(),
+    }
+}
+
+/// Print a line to stdout around the progress bar
+pub fn print_line(line: std::fmt::Arguments) {
+    suspend_progress(|| println!("{}", line));
+}
+
+/// Print a line to stderr around the progress bar
+pub fn print_error(line: std::fmt::Arguments) {
+    suspend_progress(|| eprintln!("{}", line));
+}
+
+/// Print a progress/status line unless running with `--quiet`
+#[macro_export]
+macro_rules! status {
+    ($($arg:tt)*) => {
+        if $crate::logging::enabled($crate::logging::LogLevel::Normal) {
+            $crate::logging::print_line(format_args!($($arg)*));
+        }
+    };
+}
+
+/// Print a per-run detail line only when running with `--verbose`
+#[macro_export]
+macro_rules! detail {
+    ($($arg:tt)*) => {
+        if $crate::logging::enabled($crate::logging::LogLevel::Verbose) {
+            $crate::logging::print_line(format_args!($($arg)*));
+        }
+    };
+}
+
+/// Print an error line to stderr at every level, so `--quiet` stdout holds only results
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)*) => {
+        $crate::logging::print_error(format_args!($($arg)*))
+    };
+}
+
+pub use crate::{detail, error, status};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_gating() {
+        set_level(LogLevel::Quiet);
+        assert!(enabled(LogLevel::Quiet));
+        assert!(!enabled(LogLevel::Normal));
+        assert!(!enabled(LogLevel::Verbose));
+
+        set_level(LogLevel::Verbose);
+        assert_eq!(level(), LogLevel::Verbose);
+        assert!(enabled(LogLevel::Normal));
+        assert!(enabled(LogLevel::Verbose));
+
+        set_level(LogLevel::Normal);
+        assert!(enabled(LogLevel::Normal));
+        assert!(!enabled(LogLevel::Verbose));
+    }
+
+    #[test]
+    fn test_no_color_strips_escape_codes() {
+        use colored::Colorize;
+
+        configure_color(true);
+        let line = format!("{}", format!("Seed: {}", 42).yellow());
+        let header = "=== Benchmark Results ==="
+            .bright_green()
+            .bold()
+            .to_string();
+        colored::control::unset_override();
+
+        assert_eq!(line, "Seed: 42");
+        assert!(!header.contains('\x1b'));
+    }
+}
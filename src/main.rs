@@ -1,22 +1,36 @@
 use clap::{Parser, Subcommand};
 use colored::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-// Module declarations
-mod benchmark;
-mod data_generator;
-mod geometry;
-mod matrix;
-mod sorting;
-mod visualization;
-
-use benchmark::BenchmarkRunner;
-use data_generator::DataGenerator;
+use divide_conquer_processor::{
+    benchmark, data_generator, geometry, logging, matrix, verification, visualization,
+};
+use benchmark::{estimate_runtime, BenchmarkRunner, Complexity, SORT_ALGORITHMS};
+use data_generator::{DataGenerator, DataPattern};
+use geometry::Point;
+use logging::{error, status, LogLevel};
+use matrix::{Matrix, MultiplyAlgorithm};
+use visualization::{ChartConfig, ColorScheme, OutputFormat};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Seed for data generation (random if omitted)
+    #[arg(long, global = true)]
+    seed: Option<u64>,
+    /// Only print final results and errors
+    #[arg(long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    /// Also print per-run timings
+    #[arg(long, global = true)]
+    verbose: bool,
+    /// Disable colored output (also honored via the NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    no_color: bool,
 }
 
 #[derive(Subcommand)]
@@ -32,6 +46,12 @@ enum Commands {
         /// Enable parallel processing
         #[arg(short, long)]
         parallel: bool,
+        /// Read integers from a file (newline- or comma-separated) instead of generating them
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Generated input shape: random, sorted, reverse, duplicates or partial
+        #[arg(long, default_value = "random", conflicts_with = "input")]
+        pattern: DataPattern,
     },
     /// Run matrix multiplication benchmark
     Matrix {
@@ -41,18 +61,63 @@ enum Commands {
         /// Use Strassen algorithm
         #[arg(short = 't', long)]
         strassen: bool,
+        /// Use Winograd algorithm
+        #[arg(short, long, conflicts_with = "strassen")]
+        winograd: bool,
+        /// Load the left matrix from a CSV file
+        #[arg(long, requires = "input_b")]
+        input_a: Option<String>,
+        /// Load the right matrix from a CSV file
+        #[arg(long, requires = "input_a")]
+        input_b: Option<String>,
+        /// Run every algorithm on sizes doubling from 64 up to --size and chart them
+        #[arg(long, conflicts_with_all = ["strassen", "winograd", "input_a"])]
+        compare_all: bool,
+        /// Output path for the --compare-all chart
+        #[arg(long, default_value = "matrix_comparison.png")]
+        chart: String,
     },
     /// Run closest pair problem benchmark
     Geometry {
         /// Number of points
         #[arg(short, long, default_value_t = 10000)]
         points: usize,
+        /// Read points from a CSV file of x,y lines instead of generating them
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Also run brute force to compare against divide and conquer
+        #[arg(short, long)]
+        compare: bool,
+        /// Largest point count for which brute force is run in compare mode
+        #[arg(long, default_value_t = 20000)]
+        brute_force_max: usize,
+    },
+    /// Run convex hull benchmark
+    Hull {
+        /// Number of points
+        #[arg(short, long, default_value_t = 10000)]
+        points: usize,
+    },
+    /// Run k-d tree build and nearest-neighbor query benchmark
+    Kdtree {
+        /// Number of points in the tree
+        #[arg(short, long, default_value_t = 10000)]
+        points: usize,
+        /// Number of nearest-neighbor queries
+        #[arg(short, long, default_value_t = 1000)]
+        queries: usize,
     },
     /// Comprehensive benchmark of all algorithms
     All {
         /// Use small dataset sizes
         #[arg(short, long)]
         small: bool,
+        /// Comma-separated data sizes (overrides the presets)
+        #[arg(long, value_delimiter = ',', value_parser = parse_size)]
+        sizes: Option<Vec<usize>>,
+        /// Predict the total runtime from quick calibration runs instead of benchmarking
+        #[arg(long)]
+        estimate: bool,
     },
     /// Generate visualization of results
     Visualize {
@@ -62,113 +127,570 @@ enum Commands {
         /// Output file path
         #[arg(short, long, default_value = "output.png")]
         output: String,
+        /// Output format: png, svg, report, csv or html
+        #[arg(short, long, default_value = "png")]
+        format: OutputFormat,
+        /// Chart width in pixels
+        #[arg(long, default_value_t = 1200)]
+        width: u32,
+        /// Chart height in pixels
+        #[arg(long, default_value_t = 800)]
+        height: u32,
+        /// Chart colors: default, colorblind or grayscale
+        #[arg(long, default_value = "default")]
+        colors: ColorScheme,
+    },
+    /// Verify algorithm correctness against reference implementations
+    Verify {
+        /// Data size
+        #[arg(short, long, default_value_t = 10000)]
+        size: usize,
     },
 }
 
 fn main() {
     let cli = Cli::parse();
     
-    println!("{}", "=== Large-Scale Data Processing Application ===".bright_blue().bold());
+    logging::configure_color(cli.no_color);
+    if cli.quiet {
+        logging::set_level(LogLevel::Quiet);
+    } else if cli.verbose {
+        logging::set_level(LogLevel::Verbose);
+    }
     
-    match &cli.command {
-        Commands::Sort { size, runs, parallel } => {
-            println!("{}", "Running sorting algorithms benchmark...".green());
-            run_sort_benchmark(*size, *runs, *parallel);
+    status!("{}", "=== Large-Scale Data Processing Application ===".bright_blue().bold());
+    
+    let seed = cli.seed.unwrap_or_else(rand::random);
+    status!("{}", format!("Seed: {}", seed).yellow());
+    
+    DataGenerator::with_seed(seed, || run_command(&cli.command));
+}
+
+fn run_command(command: &Commands) {
+    match command {
+        Commands::Sort { size, runs, parallel, input, pattern } => {
+            status!("{}", "Running sorting algorithms benchmark...".green());
+            let mut runner = BenchmarkRunner::new();
+            runner.set_planned_runs(sort_runs(*runs, *parallel));
+            match input {
+                Some(path) => match DataGenerator::load_integers(path) {
+                    Ok(data) => run_sort_benchmark_with_data(&mut runner, &data, *runs, *parallel),
+                    Err(e) => {
+                        error!("{}", format!("Error reading input {}: {}", path, e).red());
+                        std::process::exit(1);
+                    }
+                },
+                None => run_sort_benchmark(&mut runner, *size, *runs, *parallel, *pattern),
+            }
+            runner.display_results();
+        }
+        Commands::Matrix { size, compare_all: true, chart, .. } => {
+            status!("{}", "Comparing matrix multiplication algorithms...".green());
+            run_matrix_comparison(*size, chart);
+        }
+        Commands::Matrix { size, strassen, winograd, input_a, input_b, .. } => {
+            status!("{}", "Running matrix multiplication benchmark...".green());
+            let method = if *strassen {
+                MultiplyAlgorithm::Strassen
+            } else if *winograd {
+                MultiplyAlgorithm::Winograd
+            } else {
+                MultiplyAlgorithm::Standard
+            };
+            let mut runner = BenchmarkRunner::new();
+            match (input_a, input_b) {
+                (Some(path_a), Some(path_b)) => {
+                    match Matrix::from_csv(path_a).and_then(|a| {
+                        Matrix::from_csv(path_b).map(|b| (a, b))
+                    }) {
+                        Ok((matrix_a, matrix_b)) => {
+                            run_matrix_benchmark_with_matrices(&mut runner, &matrix_a, &matrix_b, method)
+                        }
+                        Err(e) => {
+                            error!("{}", format!("Error reading input: {}", e).red());
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                _ => run_matrix_benchmark(&mut runner, *size, method),
+            }
+            runner.display_results();
+        }
+        Commands::Geometry { points, input, compare, brute_force_max } => {
+            status!("{}", "Running closest pair problem benchmark...".green());
+            let brute_force_max = compare.then_some(*brute_force_max);
+            let mut runner = BenchmarkRunner::new();
+            match input {
+                Some(path) => match DataGenerator::load_points_csv(path) {
+                    Ok(point_set) => {
+                        run_geometry_benchmark_with_points(&mut runner, &point_set, brute_force_max)
+                    }
+                    Err(e) => {
+                        error!("{}", format!("Error reading input {}: {}", path, e).red());
+                        std::process::exit(1);
+                    }
+                },
+                None => run_geometry_benchmark(&mut runner, *points, brute_force_max),
+            }
+            runner.display_results();
+        }
+        Commands::Hull { points } => {
+            status!("{}", "Running convex hull benchmark...".green());
+            run_hull_benchmark(*points);
         }
-        Commands::Matrix { size, strassen } => {
-            println!("{}", "Running matrix multiplication benchmark...".green());
-            run_matrix_benchmark(*size, *strassen);
+        Commands::Kdtree { points, queries } => {
+            status!("{}", "Running k-d tree benchmark...".green());
+            run_kdtree_benchmark(*points, *queries);
         }
-        Commands::Geometry { points } => {
-            println!("{}", "Running closest pair problem benchmark...".green());
-            run_geometry_benchmark(*points);
+        Commands::All { small, sizes, estimate: true } => {
+            status!("{}", "Estimating comprehensive benchmark runtime...".green());
+            estimate_comprehensive_benchmark(&comprehensive_sizes(*small, sizes.as_deref()));
         }
-        Commands::All { small } => {
-            println!("{}", "Running comprehensive benchmark...".green());
-            run_comprehensive_benchmark(*small);
+        Commands::All { small, sizes, .. } => {
+            status!("{}", "Running comprehensive benchmark...".green());
+            run_comprehensive_benchmark(*small, sizes.as_deref());
         }
-        Commands::Visualize { input, output } => {
-            println!("{}", "Generating visualization...".green());
-            run_visualization(input, output);
+        Commands::Visualize { input, output, format, width, height, colors } => {
+            status!("{}", "Generating visualization...".green());
+            let config = ChartConfig {
+                color_scheme: *colors,
+                ..ChartConfig::with_size(*width, *height)
+            };
+            run_visualization(input, output, *format, &config);
+        }
+        Commands::Verify { size } => {
+            status!("{}", "Verifying algorithm correctness...".green());
+            if !run_verification(*size) {
+                std::process::exit(1);
+            }
         }
     }
 }
 
-fn run_sort_benchmark(size: usize, runs: usize, parallel: bool) {
-    let mut runner = BenchmarkRunner::new();
-    let data = DataGenerator::generate_random_integers(size);
-    
-    println!("{}", format!("Data size: {}, Number of runs: {}", size, runs).yellow());
+/// Parse a single positive data size for `--sizes`
+fn parse_size(value: &str) -> Result<usize, String> {
+    match value.trim().parse::<usize>() {
+        Ok(0) => Err("size must be positive".to_string()),
+        Ok(size) => Ok(size),
+        Err(_) => Err(format!("invalid size '{}'", value)),
+    }
+}
+
+fn run_sort_benchmark(
+    runner: &mut BenchmarkRunner,
+    size: usize,
+    runs: usize,
+    parallel: bool,
+    pattern: DataPattern,
+) {
+    status!("{}", format!("Input pattern: {}", pattern.name()).yellow());
+    let data = pattern.generate(size);
+    run_sort_benchmark_with_data(runner, &data, runs, parallel);
+}
+
+fn run_sort_benchmark_with_data(
+    runner: &mut BenchmarkRunner,
+    data: &[i32],
+    runs: usize,
+    parallel: bool,
+) {
+    status!("{}", format!("Data size: {}, Number of runs: {}", data.len(), runs).yellow());
     
     if parallel {
-        println!("{}", "Running in parallel mode".cyan());
+        status!("{}", "Running in parallel mode".cyan());
     }
     
-    // Benchmark merge sort
-    runner.benchmark_sort("Merge Sort", &data, runs, parallel);
+    for sort in &SORT_ALGORITHMS {
+        if sort.function(parallel).is_some() {
+            runner.benchmark_sort(sort.name, data, runs, parallel);
+        }
+    }
+}
+
+/// Timed runs `run_sort_benchmark_with_data` performs, for the progress bar
+fn sort_runs(runs: usize, parallel: bool) -> usize {
+    let sorts = SORT_ALGORITHMS
+        .iter()
+        .filter(|sort| sort.function(parallel).is_some())
+        .count();
+    sorts * runs
+}
+
+fn run_matrix_benchmark(runner: &mut BenchmarkRunner, size: usize, method: MultiplyAlgorithm) {
+    let (matrix_a, matrix_b) = DataGenerator::generate_random_matrices(size);
+    run_matrix_benchmark_with_matrices(runner, &matrix_a, &matrix_b, method);
+}
+
+fn run_matrix_benchmark_with_matrices(
+    runner: &mut BenchmarkRunner,
+    matrix_a: &Matrix,
+    matrix_b: &Matrix,
+    method: MultiplyAlgorithm,
+) {
+    status!(
+        "{}",
+        format!(
+            "Matrix size: {}x{} * {}x{}",
+            matrix_a.rows(),
+            matrix_a.cols(),
+            matrix_b.rows(),
+            matrix_b.cols()
+        )
+        .yellow()
+    );
     
-    // Benchmark quick sort
-    runner.benchmark_sort("Quick Sort", &data, runs, parallel);
+    if method != MultiplyAlgorithm::Standard {
+        status!("{}", format!("Using {} algorithm", method.name()).cyan());
+    }
     
-    // Display results
-    runner.display_results();
+    runner.benchmark_matrix_multiply("Matrix Multiplication", matrix_a, matrix_b, method);
 }
 
-fn run_matrix_benchmark(size: usize, strassen: bool) {
+/// Longest single multiplication allowed in --compare-all before an algorithm is dropped
+const COMPARE_ALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn run_matrix_comparison(max_size: usize, chart_path: &str) {
     let mut runner = BenchmarkRunner::new();
-    let (matrix_a, matrix_b) = DataGenerator::generate_random_matrices(size);
+    runner.set_timeout(Some(COMPARE_ALL_TIMEOUT));
     
-    println!("{}", format!("Matrix size: {}x{}", size, size).yellow());
+    let mut sizes: Vec<usize> = std::iter::successors(Some(64), |&n| Some(n * 2))
+        .take_while(|&n| n < max_size)
+        .collect();
+    sizes.push(max_size);
     
-    if strassen {
-        println!("{}", "Using Strassen algorithm".cyan());
+    // Once an algorithm times out, larger sizes would only time out again
+    let mut remaining = MultiplyAlgorithm::ALL.to_vec();
+    for size in sizes {
+        status!("{}", format!("Matrix size: {}x{}", size, size).yellow());
+        let (matrix_a, matrix_b) = DataGenerator::generate_random_matrices(size);
+        
+        remaining.retain(|&method| {
+            runner.benchmark_matrix_multiply("Matrix Multiplication", &matrix_a, &matrix_b, method);
+            !runner.get_results().last().is_some_and(|r| r.timed_out)
+        });
     }
     
-    runner.benchmark_matrix_multiply("Matrix Multiplication", &matrix_a, &matrix_b, strassen);
     runner.display_results();
+    
+    match visualization::generate_matrix_comparison_chart(
+        runner.get_results(),
+        chart_path,
+        &ChartConfig::default(),
+    ) {
+        Ok(_) => println!("{}", format!("Comparison chart saved to {}", chart_path).green()),
+        Err(e) => error!("{}", format!("Error generating chart: {}", e).red()),
+    }
+}
+
+fn run_geometry_benchmark(runner: &mut BenchmarkRunner, points: usize, brute_force_max: Option<usize>) {
+    let point_set = DataGenerator::generate_random_points(points);
+    run_geometry_benchmark_with_points(runner, &point_set, brute_force_max);
 }
 
-fn run_geometry_benchmark(points: usize) {
+fn run_geometry_benchmark_with_points(
+    runner: &mut BenchmarkRunner,
+    point_set: &[Point],
+    brute_force_max: Option<usize>,
+) {
+    status!("{}", format!("Number of points: {}", point_set.len()).yellow());
+    
+    match brute_force_max {
+        Some(limit) => {
+            runner.benchmark_closest_pair_both(point_set, limit);
+        }
+        None => runner.benchmark_closest_pair("Closest Pair", point_set),
+    }
+}
+
+fn run_hull_benchmark(points: usize) {
     let mut runner = BenchmarkRunner::new();
     let point_set = DataGenerator::generate_random_points(points);
     
-    println!("{}", format!("Number of points: {}", points).yellow());
+    status!("{}", format!("Number of points: {}", points).yellow());
     
-    runner.benchmark_closest_pair("Closest Pair", &point_set);
+    runner.benchmark_convex_hull(&point_set);
     runner.display_results();
 }
 
-fn run_comprehensive_benchmark(small: bool) {
-    println!("{}", "=== Comprehensive Benchmark ===".bright_magenta().bold());
+fn run_kdtree_benchmark(points: usize, queries: usize) {
+    let mut runner = BenchmarkRunner::new();
+    let point_set = DataGenerator::generate_random_points(points);
+    let query_set = DataGenerator::generate_random_points(queries);
     
-    let sizes = if small {
-        vec![100, 500, 1000, 5000]
+    status!(
+        "{}",
+        format!("Number of points: {}, Number of queries: {}", points, queries).yellow()
+    );
+    
+    runner.benchmark_kdtree(&point_set, &query_set);
+    runner.display_results();
+}
+
+/// Where an interrupted comprehensive benchmark saves the results it has so far
+const PARTIAL_RESULTS_FILE: &str = "partial_results.json";
+
+/// Timed runs per sort in the comprehensive benchmark
+const COMPREHENSIVE_SORT_RUNS: usize = 3;
+
+/// Matrix dimension the comprehensive benchmark uses for a data size; below 4 it is skipped
+fn comprehensive_matrix_size(size: usize) -> usize {
+    (size as f64).sqrt() as usize
+}
+
+/// Timed runs the comprehensive benchmark performs at `size`, for its overall progress bar
+fn comprehensive_runs(size: usize) -> usize {
+    let sorts = sort_runs(COMPREHENSIVE_SORT_RUNS, false) + sort_runs(COMPREHENSIVE_SORT_RUNS, true);
+    let matrices = if comprehensive_matrix_size(size) >= 4 {
+        MultiplyAlgorithm::ALL.len()
     } else {
-        vec![1000, 5000, 10000, 50000, 100000]
+        0
     };
+    // One closest pair run
+    sorts + matrices + 1
+}
+
+fn run_comprehensive_benchmark(small: bool, custom_sizes: Option<&[usize]>) {
+    status!("{}", "=== Comprehensive Benchmark ===".bright_magenta().bold());
     
-    for &size in &sizes {
-        println!("{}", format!("\n--- Data size: {} ---", size).bright_yellow());
+    // Ctrl-C stops the run between benchmark runs instead of killing the process;
+    // a second Ctrl-C exits immediately, e.g. when a single run is taking too long
+    let mut runner = BenchmarkRunner::new();
+    let interrupted = Arc::new(AtomicBool::new(false));
+    runner.set_cancel_flag(Arc::clone(&interrupted));
+    if let Err(e) = ctrlc::set_handler(move || {
+        if interrupted.swap(true, Ordering::Relaxed) {
+            std::process::exit(130);
+        }
+    }) {
+        error!("{}", format!("Could not install Ctrl-C handler: {}", e).red());
+    }
+    
+    let sizes = comprehensive_sizes(small, custom_sizes);
+    runner.set_planned_runs(sizes.iter().map(|&size| comprehensive_runs(size)).sum());
+    
+    for size in sizes {
+        if runner.is_cancelled() {
+            break;
+        }
+        status!("{}", format!("\n--- Data size: {} ---", size).bright_yellow());
         
         // Sorting algorithms
-        run_sort_benchmark(size, 3, false);
-        run_sort_benchmark(size, 3, true);
+        run_sort_benchmark(&mut runner, size, COMPREHENSIVE_SORT_RUNS, false, DataPattern::Random);
+        run_sort_benchmark(&mut runner, size, COMPREHENSIVE_SORT_RUNS, true, DataPattern::Random);
         
         // Matrix multiplication (adjust size)
-        let matrix_size = (size as f64).sqrt() as usize;
+        let matrix_size = comprehensive_matrix_size(size);
         if matrix_size >= 4 {
-            run_matrix_benchmark(matrix_size, false);
-            run_matrix_benchmark(matrix_size, true);
+            for method in MultiplyAlgorithm::ALL {
+                run_matrix_benchmark(&mut runner, matrix_size, method);
+            }
         }
         
         // Closest pair problem
-        run_geometry_benchmark(size);
+        run_geometry_benchmark(&mut runner, size, None);
+    }
+    
+    // Runs skipped after a Ctrl-C never reach the planned total
+    runner.finish_progress();
+    runner.display_results();
+    
+    if runner.is_cancelled() {
+        error!("{}", "\nInterrupted; keeping the results collected so far".yellow());
+        match runner.save_results(PARTIAL_RESULTS_FILE) {
+            Ok(_) => println!("{}", format!("Partial results saved to {}", PARTIAL_RESULTS_FILE).green()),
+            Err(e) => error!("{}", format!("Error saving partial results: {}", e).red()),
+        }
+    }
+}
+
+fn comprehensive_sizes(small: bool, custom_sizes: Option<&[usize]>) -> Vec<usize> {
+    if let Some(custom_sizes) = custom_sizes {
+        custom_sizes.to_vec()
+    } else if small {
+        vec![100, 500, 1000, 5000]
+    } else {
+        vec![1000, 5000, 10000, 50000, 100000]
+    }
+}
+
+/// Time `work` once
+fn time_once(work: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    work();
+    start.elapsed()
+}
+
+/// One algorithm's timing at a small size, ready to be extrapolated
+struct Calibration {
+    name: String,
+    size: usize,
+    time: Duration,
+    complexity: Complexity,
+    /// Timed runs per planned size
+    runs: u32,
+    /// Input size the algorithm actually gets for a planned data size
+    size_for: fn(usize) -> usize,
+}
+
+/// Predict how long `run_comprehensive_benchmark` would take on `sizes`
+/// Each algorithm is timed once at a small calibration size and extrapolated
+/// by its complexity; matrix sizes follow the sqrt(size) scaling of the real run
+fn estimate_comprehensive_benchmark(sizes: &[usize]) {
+    const SORT_CALIBRATION: usize = 20_000;
+    const MATRIX_CALIBRATION: usize = 128;
+    const POINT_CALIBRATION: usize = 20_000;
+    
+    let integers = DataGenerator::generate_random_integers(SORT_CALIBRATION);
+    let time_sort = |sort: fn(&mut [i32])| {
+        let mut data = integers.clone();
+        time_once(|| sort(&mut data))
+    };
+    let (matrix_a, matrix_b) = DataGenerator::generate_random_matrices(MATRIX_CALIBRATION);
+    let points = DataGenerator::generate_random_points(POINT_CALIBRATION);
+    
+    // The comprehensive run benchmarks every sort sequentially, then in parallel
+    let sorts = [false, true].into_iter().flat_map(|parallel| {
+        SORT_ALGORITHMS.iter().filter_map(move |sort| {
+            let name = format!("{}{}", sort.name, if parallel { " (Parallel)" } else { "" });
+            sort.function(parallel).map(|function| (name, function))
+        })
+    });
+    
+    let mut plan: Vec<Calibration> = Vec::new();
+    for (name, sort) in sorts {
+        plan.push(Calibration {
+            name,
+            size: SORT_CALIBRATION,
+            time: time_sort(sort),
+            complexity: Complexity::Linearithmic,
+            runs: COMPREHENSIVE_SORT_RUNS as u32,
+            size_for: |n| n,
+        });
+    }
+    for method in MultiplyAlgorithm::ALL {
+        let complexity = match method {
+            MultiplyAlgorithm::Strassen => Complexity::Power(7f64.log2()),
+            _ => Complexity::Cubic,
+        };
+        let elapsed = time_once(|| {
+            let _ = method.multiply(&matrix_a, &matrix_b);
+        });
+        plan.push(Calibration {
+            name: format!("Matrix Multiplication ({})", method.name()),
+            size: MATRIX_CALIBRATION,
+            time: elapsed,
+            complexity,
+            runs: 1,
+            size_for: comprehensive_matrix_size,
+        });
+    }
+    let elapsed = time_once(|| {
+        let _ = geometry::closest_pair_divide_conquer(&points);
+    });
+    plan.push(Calibration {
+        name: "Closest Pair".to_string(),
+        size: POINT_CALIBRATION,
+        time: elapsed,
+        complexity: Complexity::Linearithmic,
+        runs: 1,
+        size_for: |n| n,
+    });
+    
+    let mut total = Duration::ZERO;
+    for calibration in &plan {
+        let estimate: Duration = sizes
+            .iter()
+            .map(|&size| (calibration.size_for)(size))
+            // The real run skips matrices smaller than 4x4
+            .filter(|&size| !calibration.name.starts_with("Matrix") || size >= 4)
+            .map(|size| {
+                estimate_runtime(calibration.time, calibration.size, size, calibration.complexity)
+                    * calibration.runs
+            })
+            .sum();
+        status!("  {}: ~{:.2}ms", calibration.name, estimate.as_secs_f64() * 1000.0);
+        total += estimate;
     }
+    
+    println!(
+        "{}",
+        format!(
+            "Estimated total for sizes {:?}: ~{:.2}s (timed work only, excluding data generation)",
+            sizes,
+            total.as_secs_f64()
+        )
+        .green()
+    );
 }
 
-fn run_visualization(input: &str, output: &str) {
-    match visualization::generate_performance_charts(input, output) {
+fn run_visualization(input: &str, output: &str, format: OutputFormat, config: &ChartConfig) {
+    match visualization::generate_visualization(input, output, format, config) {
         Ok(_) => println!("{}", format!("Visualization saved to {}", output).green()),
-        Err(e) => println!("{}", format!("Error generating visualization: {}", e).red()),
+        Err(e) => error!("{}", format!("Error generating visualization: {}", e).red()),
+    }
+}
+
+fn run_verification(size: usize) -> bool {
+    let results = verification::verify_all(size);
+    
+    status!("{}", format!("Data size: {}", size).yellow());
+    
+    for result in &results {
+        let status = if result.passed {
+            "PASS".bright_green().bold()
+        } else {
+            "FAIL".bright_red().bold()
+        };
+        println!("  [{}] {} ({})", status, result.algorithm, result.detail);
+    }
+    
+    let failures = results.iter().filter(|r| !r.passed).count();
+    if failures == 0 {
+        println!("{}", format!("All {} checks passed", results.len()).green());
+    } else {
+        println!("{}", format!("{} of {} checks failed", failures, results.len()).red());
+    }
+    
+    failures == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn parse_all_sizes(sizes: &str) -> Result<Option<Vec<usize>>, clap::Error> {
+        let cli = Cli::try_parse_from(["divide-conquer-processor", "all", "--sizes", sizes])?;
+        match cli.command {
+            Commands::All { sizes, .. } => Ok(sizes),
+            _ => unreachable!(),
+        }
+    }
+    
+    #[test]
+    fn test_parse_custom_sizes() {
+        assert_eq!(
+            parse_all_sizes("100,1000,10000").unwrap(),
+            Some(vec![100, 1000, 10000])
+        );
+    }
+    
+    #[test]
+    fn test_parse_custom_sizes_rejects_invalid() {
+        assert!(parse_all_sizes("100,,1000").is_err());
+        assert!(parse_all_sizes("100,abc").is_err());
+        assert!(parse_all_sizes("0,100").is_err());
+    }
+    
+    #[test]
+    fn test_cli_definition_is_valid() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+    }
+    
+    #[test]
+    fn test_quiet_conflicts_with_verbose() {
+        let cli = Cli::try_parse_from(["divide-conquer-processor", "--quiet", "kdtree", "-q", "5"]).unwrap();
+        assert!(cli.quiet && !cli.verbose);
+        assert!(Cli::try_parse_from(["divide-conquer-processor", "sort", "--quiet", "--verbose"]).is_err());
     }
 }
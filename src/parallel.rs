@@ -0,0 +1,41 @@
+use rayon::prelude::*;
+
+/// Map every item and combine the results with Rayon
+///
+/// `reduce` must be associative and `identity` neutral for it, since Rayon
+/// may group and seed partial results arbitrarily across threads
+pub fn map_reduce<T, R>(
+    items: &[T],
+    map: impl Fn(&T) -> R + Sync + Send,
+    reduce: impl Fn(R, R) -> R + Sync + Send,
+    identity: R,
+) -> R
+where
+    T: Sync,
+    R: Clone + Send + Sync,
+{
+    items
+        .par_iter()
+        .map(map)
+        .reduce(|| identity.clone(), reduce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_generator::DataGenerator;
+
+    #[test]
+    fn test_map_reduce_sum_matches_sequential() {
+        let values = DataGenerator::generate_random_integers(100_000);
+
+        let parallel = map_reduce(&values, |&v| v as i64, |a, b| a + b, 0);
+        let sequential: i64 = values.iter().map(|&v| v as i64).sum();
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(
+            map_reduce(&[] as &[i32], |&v| v, |a, b| a.max(b), i32::MIN),
+            i32::MIN
+        );
+    }
+}
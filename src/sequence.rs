@@ -0,0 +1,81 @@
+/// Maximum-sum contiguous subarray by divide and conquer: O(n log n)
+/// Returns (start, end, sum) with `end` inclusive. The best subarray lies in the left
+/// half, the right half, or crosses the midpoint. All-negative input yields the single
+/// largest element; empty input yields (0, 0, 0)
+pub fn max_subarray(arr: &[i32]) -> (usize, usize, i64) {
+    if arr.is_empty() {
+        return (0, 0, 0);
+    }
+
+    max_subarray_recursive(arr, 0, arr.len() - 1)
+}
+
+fn max_subarray_recursive(arr: &[i32], low: usize, high: usize) -> (usize, usize, i64) {
+    if low == high {
+        return (low, high, arr[low] as i64);
+    }
+
+    let mid = low + (high - low) / 2;
+    let left = max_subarray_recursive(arr, low, mid);
+    let right = max_subarray_recursive(arr, mid + 1, high);
+    let crossing = max_crossing_subarray(arr, low, mid, high);
+
+    // Prefer the earliest subarray on ties
+    [crossing, right].into_iter().fold(left, |best, candidate| {
+        if candidate.2 > best.2 {
+            candidate
+        } else {
+            best
+        }
+    })
+}
+
+/// Best subarray that includes both arr[mid] and arr[mid + 1]
+fn max_crossing_subarray(arr: &[i32], low: usize, mid: usize, high: usize) -> (usize, usize, i64) {
+    let mut sum = 0;
+    let mut left_sum = i64::MIN;
+    let mut start = mid;
+    for i in (low..=mid).rev() {
+        sum += arr[i] as i64;
+        if sum > left_sum {
+            left_sum = sum;
+            start = i;
+        }
+    }
+
+    sum = 0;
+    let mut right_sum = i64::MIN;
+    let mut end = mid + 1;
+    for (j, &value) in arr.iter().enumerate().take(high + 1).skip(mid + 1) {
+        sum += value as i64;
+        if sum > right_sum {
+            right_sum = sum;
+            end = j;
+        }
+    }
+
+    (start, end, left_sum + right_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_subarray_mixed_signs() {
+        let arr = [
+            13, -3, -25, 20, -3, -16, -23, 18, 20, -7, 12, -5, -22, 15, -4, 7,
+        ];
+        assert_eq!(max_subarray(&arr), (7, 10, 43));
+
+        let arr = [-2, 1, -3, 4, -1, 2, 1, -5, 4];
+        assert_eq!(max_subarray(&arr), (3, 6, 6));
+    }
+
+    #[test]
+    fn test_max_subarray_all_negative() {
+        assert_eq!(max_subarray(&[-8, -3, -6, -2, -5, -4]), (3, 3, -2));
+        assert_eq!(max_subarray(&[i32::MIN, i32::MIN]), (0, 0, i32::MIN as i64));
+        assert_eq!(max_subarray(&[]), (0, 0, 0));
+    }
+}
@@ -1,92 +1,445 @@
 use rayon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::dc::{self, DivideConquer};
+
+/// Default slice length at or below which the parallel sorts run sequentially
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 1000;
+
+static PARALLEL_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_PARALLEL_THRESHOLD);
+
+/// Override the slice length at or below which the parallel sorts stop splitting
+pub fn set_parallel_threshold(threshold: usize) {
+    PARALLEL_THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+/// Current slice length at or below which the parallel sorts stop splitting
+pub fn parallel_threshold() -> usize {
+    PARALLEL_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Maximum recursion depth that still spawns parallel tasks for `threads` workers
+/// ceil(log2(threads)) levels saturate the pool; two extra levels absorb load imbalance
+pub fn parallel_depth_limit(threads: usize) -> usize {
+    let levels = (usize::BITS - threads.max(1).saturating_sub(1).leading_zeros()) as usize;
+    levels + 2
+}
+
+/// Slices at or below this length skip the framework in `merge_sort`; splitting
+/// them into separate vectors costs more than it saves
+const MERGE_SORT_BASE_LEN: usize = 64;
 
 /// Sequential merge sort implementation
+/// Runs `MergeSorter` through `dc::solve` without parallel splitting
 pub fn merge_sort(arr: &mut [i32]) {
-    let len = arr.len();
-    if len <= 1 {
+    let sorter = MergeSorter {
+        base_len: MERGE_SORT_BASE_LEN,
+    };
+    let sorted = dc::solve(&sorter, arr.to_vec(), 0);
+    arr.copy_from_slice(&sorted);
+}
+
+/// Sequential merge sort for any totally ordered `Copy` element type
+pub fn merge_sort_t<T: Ord + Copy>(arr: &mut [T]) {
+    if arr.len() <= 1 {
         return;
     }
 
-    merge_sort_recursive(arr, 0, len - 1);
+    let mid = arr.len() / 2;
+    merge_sort_t(&mut arr[..mid]);
+    merge_sort_t(&mut arr[mid..]);
+    merge_halves(arr, mid);
 }
 
-fn merge_sort_recursive(arr: &mut [i32], left: usize, right: usize) {
-    if left < right {
-        let mid = left + (right - left) / 2;
+/// Merge two sorted slices into `dest`, which must hold exactly both inputs
+/// Ties take from `left` first, so the merge is stable
+fn merge<T: Ord + Copy>(left_slice: &[T], right_slice: &[T], dest: &mut [T]) {
+    debug_assert_eq!(dest.len(), left_slice.len() + right_slice.len());
+
+    let mut i = 0;
+    let mut j = 0;
 
-        merge_sort_recursive(arr, left, mid);
-        merge_sort_recursive(arr, mid + 1, right);
-        merge(arr, left, mid, right);
+    for slot in dest.iter_mut() {
+        if j >= right_slice.len() || (i < left_slice.len() && left_slice[i] <= right_slice[j]) {
+            *slot = left_slice[i];
+            i += 1;
+        } else {
+            *slot = right_slice[j];
+            j += 1;
+        }
     }
 }
 
-fn merge(arr: &mut [i32], left: usize, mid: usize, right: usize) {
-    let left_size = mid - left + 1;
-    let right_size = right - mid;
+/// Merge the sorted runs `arr[..mid]` and `arr[mid..]` through a temporary copy
+fn merge_halves<T: Ord + Copy>(arr: &mut [T], mid: usize) {
+    let scratch = arr.to_vec();
+    merge(&scratch[..mid], &scratch[mid..], arr);
+}
+
+/// Merge sort expressed through the `DivideConquer` framework
+/// Slices at or below `base_len` are sorted with `merge_sort_t`
+///
+/// `divide` leaves the left half in the original allocation, so `combine` can
+/// merge the right half into it from the back without allocating again
+pub struct MergeSorter {
+    pub base_len: usize,
+}
 
-    let left_arr: Vec<i32> = arr[left..=mid].to_vec();
-    let right_arr: Vec<i32> = arr[mid + 1..=right].to_vec();
+impl DivideConquer for MergeSorter {
+    type Problem = Vec<i32>;
+    type Solution = Vec<i32>;
 
-    let mut i = 0;
-    let mut j = 0;
-    let mut k = left;
+    fn is_base(&self, problem: &Vec<i32>) -> bool {
+        problem.len() <= self.base_len.max(1)
+    }
+
+    fn solve_base(&self, mut problem: Vec<i32>) -> Vec<i32> {
+        merge_sort_t(&mut problem);
+        problem
+    }
+
+    fn divide(&self, mut problem: Vec<i32>) -> (Vec<i32>, Vec<i32>) {
+        let right = problem.split_off(problem.len() / 2);
+        (problem, right)
+    }
 
-    while i < left_size && j < right_size {
-        if left_arr[i] <= right_arr[j] {
-            arr[k] = left_arr[i];
+    fn combine(&self, left: Vec<i32>, right: Vec<i32>) -> Vec<i32> {
+        let mut i = left.len();
+        let mut merged = left;
+        merged.resize(i + right.len(), 0);
+
+        // Fill from the back; ties take from `right` so the merge stays stable
+        let mut j = right.len();
+        for k in (0..merged.len()).rev() {
+            if j == 0 {
+                break;
+            }
+            if i > 0 && merged[i - 1] > right[j - 1] {
+                merged[k] = merged[i - 1];
+                i -= 1;
+            } else {
+                merged[k] = right[j - 1];
+                j -= 1;
+            }
+        }
+        merged
+    }
+}
+
+/// Merge sort driven by `dc::solve`, parallel down to the adaptive depth limit
+pub fn merge_sort_dc(arr: &mut [i32]) {
+    let sorter = MergeSorter {
+        base_len: parallel_threshold(),
+    };
+    let depth = parallel_depth_limit(rayon::current_num_threads());
+    let sorted = dc::solve(&sorter, arr.to_vec(), depth);
+    arr.copy_from_slice(&sorted);
+}
+
+/// Top-down merge sort with a single scratch buffer allocated up front
+/// Alternates ("ping-pongs") between the array and the buffer at each level,
+/// so merges never allocate, unlike `merge_sort`'s per-merge temporaries
+///
+/// Not in-place in the strict sense: the buffer is a full copy of `arr`, so it
+/// still uses O(n) extra memory, just in one allocation instead of one per merge
+pub fn merge_sort_in_place(arr: &mut [i32]) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let mut buffer = arr.to_vec();
+    merge_sort_ping_pong(&mut buffer, arr);
+}
+
+/// Sort `src` into `dest`; both must start with identical contents
+fn merge_sort_ping_pong(src: &mut [i32], dest: &mut [i32]) {
+    if dest.len() <= 1 {
+        return;
+    }
+
+    let mid = dest.len() / 2;
+    {
+        let (src_left, src_right) = src.split_at_mut(mid);
+        let (dest_left, dest_right) = dest.split_at_mut(mid);
+        // Swap roles so the sorted halves land in `src`
+        merge_sort_ping_pong(dest_left, src_left);
+        merge_sort_ping_pong(dest_right, src_right);
+    }
+
+    merge(&src[..mid], &src[mid..], dest);
+}
+
+/// Count pairs (i, j) with i < j and arr[i] > arr[j] in O(n log n)
+/// Works on a copy, so the caller's slice is left untouched
+pub fn count_inversions(arr: &[i32]) -> u64 {
+    let mut work = arr.to_vec();
+    let mut buffer = work.clone();
+    count_inversions_recursive(&mut work, &mut buffer)
+}
+
+fn count_inversions_recursive(arr: &mut [i32], buffer: &mut [i32]) -> u64 {
+    if arr.len() <= 1 {
+        return 0;
+    }
+
+    let mid = arr.len() / 2;
+    let mut inversions = {
+        let (left, right) = arr.split_at_mut(mid);
+        let (left_buffer, right_buffer) = buffer.split_at_mut(mid);
+        count_inversions_recursive(left, left_buffer)
+            + count_inversions_recursive(right, right_buffer)
+    };
+
+    // Taking from the right jumps ahead of every element left in the left half
+    let (left, right) = arr.split_at(mid);
+    let (mut i, mut j) = (0, 0);
+    for slot in buffer.iter_mut() {
+        if j >= right.len() || (i < left.len() && left[i] <= right[j]) {
+            *slot = left[i];
             i += 1;
         } else {
-            arr[k] = right_arr[j];
+            *slot = right[j];
             j += 1;
+            inversions += (left.len() - i) as u64;
         }
-        k += 1;
     }
+    arr.copy_from_slice(buffer);
 
-    while i < left_size {
-        arr[k] = left_arr[i];
-        i += 1;
-        k += 1;
+    inversions
+}
+
+/// Sort with `merge_sort`, then drop consecutive duplicates in place
+pub fn sort_and_dedup(arr: &mut Vec<i32>) {
+    merge_sort(arr);
+    arr.dedup();
+}
+
+/// Number of distinct values, without mutating the input
+pub fn count_distinct(arr: &[i32]) -> usize {
+    let mut sorted = arr.to_vec();
+    merge_sort(&mut sorted);
+    sorted.windows(2).filter(|pair| pair[0] != pair[1]).count() + usize::from(!sorted.is_empty())
+}
+
+/// Stable argsort: indices that would sort `arr` ascending
+/// Equal values keep their original relative order
+pub fn merge_argsort(arr: &[i32]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..arr.len()).collect();
+    let mut buffer = indices.clone();
+    argsort_recursive(arr, &mut indices, &mut buffer);
+    indices
+}
+
+fn argsort_recursive(arr: &[i32], indices: &mut [usize], buffer: &mut [usize]) {
+    if indices.len() <= 1 {
+        return;
+    }
+
+    let mid = indices.len() / 2;
+    {
+        let (left, right) = indices.split_at_mut(mid);
+        let (left_buffer, right_buffer) = buffer.split_at_mut(mid);
+        argsort_recursive(arr, left, left_buffer);
+        argsort_recursive(arr, right, right_buffer);
+    }
+
+    let (left, right) = indices.split_at(mid);
+    let (mut i, mut j) = (0, 0);
+    for slot in buffer.iter_mut() {
+        if j >= right.len() || (i < left.len() && arr[left[i]] <= arr[right[j]]) {
+            *slot = left[i];
+            i += 1;
+        } else {
+            *slot = right[j];
+            j += 1;
+        }
+    }
+    indices.copy_from_slice(buffer);
+}
+
+/// Time spent in each phase of an instrumented merge sort
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MergeSortPhases {
+    /// Splitting and recursion bookkeeping (total time minus merging)
+    pub recurse: Duration,
+    /// Time inside `merge`
+    pub merge: Duration,
+}
+
+/// `merge_sort` with every merge timed; slower than the plain version
+pub fn merge_sort_instrumented(arr: &mut [i32]) -> MergeSortPhases {
+    let start = Instant::now();
+    let mut merge_time = Duration::ZERO;
+
+    merge_sort_recursive_timed(arr, &mut merge_time);
+
+    MergeSortPhases {
+        recurse: start.elapsed().saturating_sub(merge_time),
+        merge: merge_time,
+    }
+}
+
+fn merge_sort_recursive_timed(arr: &mut [i32], merge_time: &mut Duration) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let mid = arr.len() / 2;
+    merge_sort_recursive_timed(&mut arr[..mid], merge_time);
+    merge_sort_recursive_timed(&mut arr[mid..], merge_time);
+
+    let start = Instant::now();
+    merge_halves(arr, mid);
+    *merge_time += start.elapsed();
+}
+
+/// Bottom-up iterative merge sort
+/// Merges runs of width 1, 2, 4, ... using one scratch buffer and no recursion
+pub fn merge_sort_iterative(arr: &mut [i32]) {
+    let len = arr.len();
+    if len <= 1 {
+        return;
     }
 
-    while j < right_size {
-        arr[k] = right_arr[j];
-        j += 1;
-        k += 1;
+    let mut buffer = vec![0; len];
+    let mut width = 1;
+
+    while width < len {
+        let mut left = 0;
+        while left < len {
+            let mid = left.saturating_add(width).min(len);
+            let right = mid.saturating_add(width).min(len);
+            merge(&arr[left..mid], &arr[mid..right], &mut buffer[left..right]);
+            left = right;
+        }
+
+        arr.copy_from_slice(&buffer);
+        width = width.saturating_mul(2);
     }
 }
 
 /// Parallel merge sort using Rayon
 pub fn parallel_merge_sort(arr: &mut [i32]) {
-    if arr.len() <= 1000 {
+    let max_depth = parallel_depth_limit(rayon::current_num_threads());
+    parallel_merge_sort_recursive(arr, 0, 0, max_depth);
+}
+
+/// `offset` is the position of `arr` within the caller's slice, used in diagnostics
+fn parallel_merge_sort_recursive(arr: &mut [i32], offset: usize, depth: usize, max_depth: usize) {
+    if arr.len() <= parallel_threshold().max(1) || depth >= max_depth {
         merge_sort(arr);
         return;
     }
 
-    // Use Rayon's parallel sort for actual parallel processing
-    arr.par_sort_unstable();
+    let mid = arr.len() / 2;
+    let (left, right) = arr.split_at_mut(mid);
+    rayon::join(
+        || parallel_merge_sort_recursive(left, offset, depth + 1, max_depth),
+        || parallel_merge_sort_recursive(right, offset + mid, depth + 1, max_depth),
+    );
+
+    merge_halves(arr, mid);
+
+    if cfg!(debug_assertions) {
+        check_sorted_range(arr, offset);
+    }
+}
+
+/// Panic with the offending indices if `arr` (starting at `offset`) is not sorted
+/// Called from the parallel sorts in debug builds only
+fn check_sorted_range(arr: &[i32], offset: usize) {
+    if let Some(i) = arr.windows(2).position(|pair| pair[0] > pair[1]) {
+        panic!(
+            "unsorted range {}..{}: index {} ({}) > index {} ({})",
+            offset,
+            offset + arr.len(),
+            offset + i,
+            arr[i],
+            offset + i + 1,
+            arr[i + 1]
+        );
+    }
 }
 
 /// Sequential quick sort implementation
 pub fn quick_sort(arr: &mut [i32]) {
-    if arr.len() <= 1 {
-        return;
+    quick_sort_t(arr);
+}
+
+/// Sequential quick sort for any totally ordered `Copy` element type
+pub fn quick_sort_t<T: Ord + Copy>(arr: &mut [T]) {
+    quick_sort_by(arr, &T::cmp);
+}
+
+/// Recurses only into the smaller partition and loops on the larger one, so the
+/// stack depth stays O(log n) however unbalanced the splits are
+fn quick_sort_by<T, F: Fn(&T, &T) -> std::cmp::Ordering>(mut arr: &mut [T], compare: &F) {
+    while arr.len() > 1 {
+        move_median_of_three_to_front(arr, compare);
+        let pivot_index = partition_around_first(arr, compare);
+
+        let (less, rest) = std::mem::take(&mut arr).split_at_mut(pivot_index);
+        let greater = &mut rest[1..];
+        if less.len() < greater.len() {
+            quick_sort_by(less, compare);
+            arr = greater;
+        } else {
+            quick_sort_by(greater, compare);
+            arr = less;
+        }
     }
+}
 
-    quick_sort_recursive(arr, 0, arr.len() - 1);
+/// Place the median of the first, middle and last elements at index 0 to
+/// serve as the pivot, so sorted and reverse-sorted ranges split evenly
+fn move_median_of_three_to_front<T, F: Fn(&T, &T) -> std::cmp::Ordering>(
+    arr: &mut [T],
+    compare: &F,
+) {
+    let (mid, last) = (arr.len() / 2, arr.len() - 1);
+    if compare(&arr[mid], &arr[0]) == std::cmp::Ordering::Less {
+        arr.swap(mid, 0);
+    }
+    if compare(&arr[last], &arr[0]) == std::cmp::Ordering::Less {
+        arr.swap(last, 0);
+    }
+    if compare(&arr[last], &arr[mid]) == std::cmp::Ordering::Less {
+        arr.swap(last, mid);
+    }
+    arr.swap(0, mid);
 }
 
-fn quick_sort_recursive(arr: &mut [i32], low: usize, high: usize) {
-    if low < high {
-        let pivot_index = partition(arr, low, high);
+/// Hoare partition around the pivot at index 0, returning the pivot's final index
+/// Both scans stop on keys equal to the pivot, so runs of equal keys are split
+/// evenly instead of all landing on one side
+fn partition_around_first<T, F: Fn(&T, &T) -> std::cmp::Ordering>(
+    arr: &mut [T],
+    compare: &F,
+) -> usize {
+    let (mut i, mut j) = (0, arr.len());
 
-        if pivot_index > 0 {
-            quick_sort_recursive(arr, low, pivot_index - 1);
+    loop {
+        i += 1;
+        while i < arr.len() && compare(&arr[i], &arr[0]) == std::cmp::Ordering::Less {
+            i += 1;
+        }
+        // Stops at index 0 at the latest, since the pivot is not greater than itself
+        j -= 1;
+        while compare(&arr[j], &arr[0]) == std::cmp::Ordering::Greater {
+            j -= 1;
         }
-        quick_sort_recursive(arr, pivot_index + 1, high);
+        if i >= j {
+            break;
+        }
+        arr.swap(i, j);
     }
+
+    arr.swap(0, j);
+    j
 }
 
-fn partition(arr: &mut [i32], low: usize, high: usize) -> usize {
+fn partition<T: Ord + Copy>(arr: &mut [T], low: usize, high: usize) -> usize {
     let pivot = arr[high];
     let mut i = low;
 
@@ -101,9 +454,209 @@ fn partition(arr: &mut [i32], low: usize, high: usize) -> usize {
     i
 }
 
+/// f64 ordered by `total_cmp`, except that every NaN sorts after all numbers
+#[derive(Debug, Clone, Copy)]
+struct NanLast(f64);
+
+impl PartialEq for NanLast {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for NanLast {}
+
+impl PartialOrd for NanLast {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NanLast {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (false, false) => self.0.total_cmp(&other.0),
+            (true, true) => std::cmp::Ordering::Equal,
+            (true, false) => std::cmp::Ordering::Greater,
+            (false, true) => std::cmp::Ordering::Less,
+        }
+    }
+}
+
+/// Merge sort for floats; NaNs are placed after all numbers and -0.0 before 0.0
+pub fn sort_f64(arr: &mut [f64]) {
+    let mut keyed: Vec<NanLast> = arr.iter().map(|&x| NanLast(x)).collect();
+    merge_sort_t(&mut keyed);
+    for (slot, key) in arr.iter_mut().zip(keyed) {
+        *slot = key.0;
+    }
+}
+
+/// Direction for the `*_ordered` sort variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    /// Whether `a` may be placed before `b` in this order (equal keys keep their position)
+    fn allows<T: Ord>(self, a: &T, b: &T) -> bool {
+        self.compare(a, b) != std::cmp::Ordering::Greater
+    }
+
+    /// `Ord::cmp`, reversed for `Descending`
+    fn compare<T: Ord>(self, a: &T, b: &T) -> std::cmp::Ordering {
+        match self {
+            SortOrder::Ascending => a.cmp(b),
+            SortOrder::Descending => b.cmp(a),
+        }
+    }
+}
+
+/// Stable merge sort in the given order
+pub fn merge_sort_ordered<T: Ord + Clone>(arr: &mut [T], order: SortOrder) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let mid = arr.len() / 2;
+    merge_sort_ordered(&mut arr[..mid], order);
+    merge_sort_ordered(&mut arr[mid..], order);
+
+    let left = arr[..mid].to_vec();
+    let right = arr[mid..].to_vec();
+    let (mut i, mut j) = (0, 0);
+
+    for slot in arr.iter_mut() {
+        if j >= right.len() || (i < left.len() && order.allows(&left[i], &right[j])) {
+            *slot = left[i].clone();
+            i += 1;
+        } else {
+            *slot = right[j].clone();
+            j += 1;
+        }
+    }
+}
+
+/// Quick sort in the given order; shares `quick_sort`'s pivot choice and
+/// O(log n) stack bound, with the comparison flipped for `Descending`
+pub fn quick_sort_ordered<T: Ord>(arr: &mut [T], order: SortOrder) {
+    quick_sort_by(arr, &|a: &T, b: &T| order.compare(a, b));
+}
+
+/// Find the k-th smallest element (0-based) using quickselect
+/// Expected O(n), but O(n²) worst case with the last-element pivot
+pub fn quickselect(arr: &mut [i32], k: usize) -> Option<i32> {
+    if k >= arr.len() {
+        return None;
+    }
+
+    let mut low = 0;
+    let mut high = arr.len() - 1;
+
+    while low < high {
+        let pivot_index = partition(arr, low, high);
+
+        match k.cmp(&pivot_index) {
+            std::cmp::Ordering::Less => high = pivot_index - 1,
+            std::cmp::Ordering::Greater => low = pivot_index + 1,
+            std::cmp::Ordering::Equal => return Some(arr[k]),
+        }
+    }
+
+    Some(arr[k])
+}
+
+/// Find the k-th smallest element (0-based) using the median-of-medians pivot
+/// Guaranteed O(n) worst case
+pub fn quickselect_deterministic(arr: &mut [i32], k: usize) -> Option<i32> {
+    if k >= arr.len() {
+        return None;
+    }
+
+    let mut low = 0;
+    let mut high = arr.len();
+
+    loop {
+        if high - low <= 5 {
+            arr[low..high].sort_unstable();
+            return Some(arr[k]);
+        }
+
+        let pivot = median_of_medians(&arr[low..high]);
+        let (less_end, greater_start) = partition_three_way(&mut arr[low..high], pivot);
+
+        if k < low + less_end {
+            high = low + less_end;
+        } else if k >= low + greater_start {
+            low += greater_start;
+        } else {
+            return Some(pivot);
+        }
+    }
+}
+
+/// Put the `k` smallest elements, sorted, at the front; the rest are left in any order
+/// Selection partitions around the k-th smallest in O(n), then only the prefix is
+/// sorted, for O(n + k log k) overall; `k >= arr.len()` sorts everything
+pub fn partial_sort(arr: &mut [i32], k: usize) {
+    if k == 0 {
+        return;
+    }
+    if k >= arr.len() {
+        arr.sort_unstable();
+        return;
+    }
+
+    // Leaves the k smallest elements in arr[..k]
+    quickselect_deterministic(arr, k - 1);
+    arr[..k].sort_unstable();
+}
+
+/// Median of the medians of groups of five
+fn median_of_medians(arr: &[i32]) -> i32 {
+    let mut medians: Vec<i32> = arr
+        .chunks(5)
+        .map(|chunk| {
+            let mut group = chunk.to_vec();
+            group.sort_unstable();
+            group[group.len() / 2]
+        })
+        .collect();
+
+    let mid = medians.len() / 2;
+    quickselect_deterministic(&mut medians, mid).unwrap()
+}
+
+/// Partition into < pivot, == pivot, > pivot regions
+/// Returns the end of the less-than region and the start of the greater-than region
+fn partition_three_way(arr: &mut [i32], pivot: i32) -> (usize, usize) {
+    let mut less_end = 0;
+    let mut i = 0;
+    let mut greater_start = arr.len();
+
+    while i < greater_start {
+        if arr[i] < pivot {
+            arr.swap(i, less_end);
+            less_end += 1;
+            i += 1;
+        } else if arr[i] > pivot {
+            greater_start -= 1;
+            arr.swap(i, greater_start);
+        } else {
+            i += 1;
+        }
+    }
+
+    (less_end, greater_start)
+}
+
 /// Parallel quick sort using Rayon
+/// Only the sequential cutoff is adaptive: above it Rayon's pattern-defeating sort
+/// does the splitting, which stays balanced on sorted and reverse-sorted input
 pub fn parallel_quick_sort(arr: &mut [i32]) {
-    if arr.len() <= 1000 {
+    if arr.len() <= parallel_threshold() {
         quick_sort(arr);
         return;
     }
@@ -112,34 +665,1061 @@ pub fn parallel_quick_sort(arr: &mut [i32]) {
     arr.par_sort_unstable();
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Ciura's empirically tuned gaps, extended by ×2.25 for large inputs
+const CIURA_GAPS: [usize; 8] = [1, 4, 10, 23, 57, 132, 301, 701];
 
-    #[test]
-    fn test_merge_sort() {
-        let mut arr = vec![64, 34, 25, 12, 22, 11, 90];
-        merge_sort(&mut arr);
-        assert_eq!(arr, vec![11, 12, 22, 25, 34, 64, 90]);
+/// Shell sort using the Ciura gap sequence
+pub fn shell_sort(arr: &mut [i32]) {
+    let len = arr.len();
+    let mut gaps = CIURA_GAPS.to_vec();
+    while let Some(&last) = gaps.last() {
+        let next = (last as f64 * 2.25) as usize;
+        if next >= len {
+            break;
+        }
+        gaps.push(next);
     }
 
-    #[test]
-    fn test_quick_sort() {
-        let mut arr = vec![64, 34, 25, 12, 22, 11, 90];
-        quick_sort(&mut arr);
-        assert_eq!(arr, vec![11, 12, 22, 25, 34, 64, 90]);
+    for &gap in gaps.iter().rev().filter(|&&gap| gap < len) {
+        for i in gap..len {
+            let value = arr[i];
+            let mut j = i;
+            while j >= gap && arr[j - gap] > value {
+                arr[j] = arr[j - gap];
+                j -= gap;
+            }
+            arr[j] = value;
+        }
+    }
+}
+
+/// Cycle sort: places every element directly at its final index
+/// Performs the minimum possible number of writes to the array
+pub fn cycle_sort(arr: &mut [i32]) {
+    cycle_sort_counting_writes(arr);
+}
+
+/// Cycle sort that returns the number of array writes performed
+fn cycle_sort_counting_writes(arr: &mut [i32]) -> usize {
+    let mut writes = 0;
+
+    for start in 0..arr.len().saturating_sub(1) {
+        let mut item = arr[start];
+
+        let mut pos = start + arr[start + 1..].iter().filter(|&&x| x < item).count();
+        if pos == start {
+            continue;
+        }
+
+        while arr[pos] == item {
+            pos += 1;
+        }
+        std::mem::swap(&mut arr[pos], &mut item);
+        writes += 1;
+
+        while pos != start {
+            pos = start + arr[start + 1..].iter().filter(|&&x| x < item).count();
+            while pos != start && arr[pos] == item {
+                pos += 1;
+            }
+            if pos == start {
+                arr[start] = item;
+            } else {
+                std::mem::swap(&mut arr[pos], &mut item);
+            }
+            writes += 1;
+        }
     }
 
-    #[test]
-    fn test_parallel_sorts() {
-        let mut arr1 = vec![64, 34, 25, 12, 22, 11, 90];
-        let mut arr2 = arr1.clone();
+    writes
+}
 
-        parallel_merge_sort(&mut arr1);
-        parallel_quick_sort(&mut arr2);
+/// Bitonic sorting network
+/// The input is copied into a buffer padded to the next power of two with `i32::MAX`
+/// sentinels, which sort to the end and are dropped when copying back.
+/// Each compare-exchange stage runs on Rayon once the buffer exceeds the parallel threshold
+pub fn bitonic_sort(arr: &mut [i32]) {
+    let len = arr.len();
+    if len <= 1 {
+        return;
+    }
 
-        let expected = vec![11, 12, 22, 25, 34, 64, 90];
-        assert_eq!(arr1, expected);
-        assert_eq!(arr2, expected);
+    let padded_len = len.next_power_of_two();
+    let mut buffer = Vec::with_capacity(padded_len);
+    buffer.extend_from_slice(arr);
+    buffer.resize(padded_len, i32::MAX);
+
+    let parallel = padded_len > parallel_threshold();
+
+    let mut k = 2;
+    while k <= padded_len {
+        let mut j = k / 2;
+        while j > 0 {
+            // Blocks of 2j pair index i with i + j; the direction is fixed per block
+            let stage = |(block, chunk): (usize, &mut [i32])| {
+                let ascending = (block * 2 * j) & k == 0;
+                let (low, high) = chunk.split_at_mut(j);
+                for (a, b) in low.iter_mut().zip(high.iter_mut()) {
+                    if (*a > *b) == ascending {
+                        std::mem::swap(a, b);
+                    }
+                }
+            };
+
+            if parallel {
+                buffer.par_chunks_mut(2 * j).enumerate().for_each(stage);
+            } else {
+                buffer.chunks_mut(2 * j).enumerate().for_each(stage);
+            }
+            j /= 2;
+        }
+        k *= 2;
+    }
+
+    arr.copy_from_slice(&buffer[..len]);
+}
+
+/// Counting sort for integers with a bounded value range
+/// Time complexity: O(n + k) where k = max - min + 1
+/// Allocates one counter per value in the range; ranges wider than
+/// `counting_sort_max_range` fall back to `radix_sort` instead
+pub fn counting_sort(arr: &mut [i32]) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let min_val = *arr.iter().min().unwrap();
+    let max_val = *arr.iter().max().unwrap();
+    let range = (max_val as i64 - min_val as i64 + 1) as usize;
+    if range > counting_sort_max_range(arr.len()) {
+        radix_sort(arr);
+        return;
+    }
+
+    let mut counts = vec![0usize; range];
+    for &value in arr.iter() {
+        counts[(value as i64 - min_val as i64) as usize] += 1;
+    }
+
+    let mut index = 0;
+    for (offset, &count) in counts.iter().enumerate() {
+        let value = (min_val as i64 + offset as i64) as i32;
+        arr[index..index + count].fill(value);
+        index += count;
+    }
+}
+
+/// Widest value range `counting_sort` allocates counters for: a few per element,
+/// plus a fixed allowance so small inputs with moderate ranges still qualify
+fn counting_sort_max_range(len: usize) -> usize {
+    4 * len + (1 << 16)
+}
+
+const RADIX: usize = 10;
+
+fn radix_digit(key: u64, exp: u64) -> usize {
+    ((key / exp) % RADIX as u64) as usize
+}
+
+/// Offset every value by the minimum so keys are non-negative
+/// Returns the keys and the minimum needed to restore the values
+/// The offset is done in i64, so a full i32::MIN..=i32::MAX span cannot overflow
+fn radix_keys(arr: &[i32]) -> (Vec<u64>, i64) {
+    let min_val = *arr.iter().min().unwrap() as i64;
+    let keys = arr.iter().map(|&v| (v as i64 - min_val) as u64).collect();
+    (keys, min_val)
+}
+
+fn restore_radix_keys(arr: &mut [i32], keys: &[u64], min_val: i64) {
+    for (slot, &key) in arr.iter_mut().zip(keys) {
+        *slot = (key as i64 + min_val) as i32;
+    }
+}
+
+/// LSD radix sort using base-10 digits
+/// Time complexity: O(d * n) where d is the number of digits in the largest key
+pub fn radix_sort(arr: &mut [i32]) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let (mut keys, min_val) = radix_keys(arr);
+    let max_key = *keys.iter().max().unwrap();
+    let mut buffer = vec![0u64; keys.len()];
+    let mut exp = 1u64;
+
+    while max_key / exp > 0 {
+        // Counting sort by the current digit
+        let mut counts = [0usize; RADIX];
+        for &key in &keys {
+            counts[radix_digit(key, exp)] += 1;
+        }
+
+        let mut positions = [0usize; RADIX];
+        for digit in 1..RADIX {
+            positions[digit] = positions[digit - 1] + counts[digit - 1];
+        }
+
+        for &key in &keys {
+            let digit = radix_digit(key, exp);
+            buffer[positions[digit]] = key;
+            positions[digit] += 1;
+        }
+
+        std::mem::swap(&mut keys, &mut buffer);
+        exp *= RADIX as u64;
+    }
+
+    restore_radix_keys(arr, &keys, min_val);
+}
+
+/// LSD radix sort over the eight bytes of each key
+/// Always exactly 8 counting passes, independent of the magnitude of the values
+pub fn radix_sort_u64(arr: &mut [u64]) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let mut buffer = vec![0u64; arr.len()];
+    let (mut source, mut target): (&mut [u64], &mut [u64]) = (arr, &mut buffer);
+
+    for shift in (0..64).step_by(8) {
+        let byte = |key: u64| ((key >> shift) & 0xFF) as usize;
+
+        let mut positions = [0usize; 256];
+        for &key in source.iter() {
+            positions[byte(key)] += 1;
+        }
+        let mut total = 0;
+        for slot in positions.iter_mut() {
+            let count = *slot;
+            *slot = total;
+            total += count;
+        }
+
+        for &key in source.iter() {
+            let digit = byte(key);
+            target[positions[digit]] = key;
+            positions[digit] += 1;
+        }
+
+        std::mem::swap(&mut source, &mut target);
+    }
+
+    // An even number of passes leaves the sorted keys back in `arr`
+}
+
+/// Parallel LSD radix sort using Rayon
+/// Each digit pass builds per-chunk histograms in parallel, prefix-sums them into
+/// disjoint output ranges, then scatters every chunk into its ranges in parallel
+/// Inputs and chunks are never smaller than `parallel_threshold()`
+pub fn parallel_radix_sort(arr: &mut [i32]) {
+    let threshold = parallel_threshold().max(1);
+    if arr.len() <= threshold {
+        radix_sort(arr);
+        return;
+    }
+
+    let (mut keys, min_val) = radix_keys(arr);
+    let max_key = *keys.par_iter().max().unwrap();
+    let mut buffer = vec![0u64; keys.len()];
+    let chunk_size = keys
+        .len()
+        .div_ceil(rayon::current_num_threads())
+        .max(threshold);
+    let mut exp = 1u64;
+
+    while max_key / exp > 0 {
+        let histograms: Vec<[usize; RADIX]> = keys
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let mut counts = [0usize; RADIX];
+                for &key in chunk {
+                    counts[radix_digit(key, exp)] += 1;
+                }
+                counts
+            })
+            .collect();
+
+        // Carve the output into one slice per (chunk, digit), ordered by digit then chunk
+        let mut targets: Vec<Vec<&mut [u64]>> = (0..histograms.len())
+            .map(|_| Vec::with_capacity(RADIX))
+            .collect();
+        let mut remaining: &mut [u64] = &mut buffer;
+        for digit in 0..RADIX {
+            for (chunk_index, counts) in histograms.iter().enumerate() {
+                let (target, rest) = std::mem::take(&mut remaining).split_at_mut(counts[digit]);
+                targets[chunk_index].push(target);
+                remaining = rest;
+            }
+        }
+
+        keys.par_chunks(chunk_size)
+            .zip(targets.into_par_iter())
+            .for_each(|(chunk, mut slices)| {
+                let mut offsets = [0usize; RADIX];
+                for &key in chunk {
+                    let digit = radix_digit(key, exp);
+                    slices[digit][offsets[digit]] = key;
+                    offsets[digit] += 1;
+                }
+            });
+
+        std::mem::swap(&mut keys, &mut buffer);
+        exp *= RADIX as u64;
+    }
+
+    restore_radix_keys(arr, &keys, min_val);
+}
+
+/// Bucket sort for (roughly) uniformly distributed finite floats
+/// Expected time complexity: O(n + bucket_count) for uniform input
+/// NaNs are moved to the end, as in `sort_f64`
+pub fn bucket_sort(arr: &mut [f64], bucket_count: usize) {
+    let mut numbers = 0;
+    for i in 0..arr.len() {
+        if !arr[i].is_nan() {
+            arr.swap(numbers, i);
+            numbers += 1;
+        }
+    }
+    let arr = &mut arr[..numbers];
+
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let bucket_count = bucket_count.max(1);
+    let min_val = arr.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max_val = arr.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = max_val - min_val;
+
+    if range == 0.0 {
+        return;
+    }
+    // An infinite range leaves nothing to scale bucket indices by
+    if !range.is_finite() {
+        arr.sort_by(f64::total_cmp);
+        return;
+    }
+
+    let mut buckets: Vec<Vec<f64>> = vec![Vec::new(); bucket_count];
+    for &value in arr.iter() {
+        let index = (((value - min_val) / range) * bucket_count as f64) as usize;
+        buckets[index.min(bucket_count - 1)].push(value);
+    }
+
+    let mut index = 0;
+    for mut bucket in buckets {
+        bucket.sort_by(f64::total_cmp);
+        arr[index..index + bucket.len()].copy_from_slice(&bucket);
+        index += bucket.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_generator::DataGenerator;
+    use std::cell::RefCell;
+    use std::cmp::Ordering;
+
+    /// State of McIlroy's "antiquicksort" adversary: items start as gas (above
+    /// every solid value) and are frozen to the next solid value only when a
+    /// comparison forces it, always in the way that keeps the pivot extreme
+    struct Adversary {
+        values: Vec<Option<i32>>,
+        next_solid: i32,
+        candidate: usize,
+        comparisons: usize,
+    }
+
+    thread_local! {
+        static ADVERSARY: RefCell<Adversary> = const {
+            RefCell::new(Adversary {
+                values: Vec::new(),
+                next_solid: 0,
+                candidate: 0,
+                comparisons: 0,
+            })
+        };
+    }
+
+    /// Item identified by its starting position, compared through `ADVERSARY`
+    #[derive(Clone, Copy)]
+    struct Gas(usize);
+
+    impl Ord for Gas {
+        fn cmp(&self, other: &Self) -> Ordering {
+            ADVERSARY.with(|adversary| {
+                let state = &mut *adversary.borrow_mut();
+                state.comparisons += 1;
+                let (x, y) = (self.0, other.0);
+                if state.values[x].is_none() && state.values[y].is_none() {
+                    let frozen = if x == state.candidate { x } else { y };
+                    state.values[frozen] = Some(state.next_solid);
+                    state.next_solid += 1;
+                }
+                if state.values[x].is_none() {
+                    state.candidate = x;
+                } else if state.values[y].is_none() {
+                    state.candidate = y;
+                }
+                let gas = i32::MAX;
+                state.values[x]
+                    .unwrap_or(gas)
+                    .cmp(&state.values[y].unwrap_or(gas))
+            })
+        }
+    }
+
+    impl PartialOrd for Gas {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl PartialEq for Gas {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl Eq for Gas {}
+
+    /// Input of size `n` that drives `quick_sort`'s pivot choice to its worst
+    /// case, with the number of comparisons `quick_sort` makes on it
+    fn quick_sort_adversary(n: usize) -> (Vec<i32>, usize) {
+        ADVERSARY.with(|adversary| {
+            *adversary.borrow_mut() = Adversary {
+                values: vec![None; n],
+                next_solid: 0,
+                candidate: 0,
+                comparisons: 0,
+            }
+        });
+        let mut items: Vec<Gas> = (0..n).map(Gas).collect();
+        quick_sort_t(&mut items);
+
+        ADVERSARY.with(|adversary| {
+            let state = &mut *adversary.borrow_mut();
+            let mut next_solid = state.next_solid;
+            let input = state
+                .values
+                .iter()
+                .map(|value| {
+                    value.unwrap_or_else(|| {
+                        next_solid += 1;
+                        next_solid - 1
+                    })
+                })
+                .collect();
+            (input, state.comparisons)
+        })
+    }
+
+    #[test]
+    fn test_merge_sort() {
+        let mut arr = vec![64, 34, 25, 12, 22, 11, 90];
+        merge_sort(&mut arr);
+        assert_eq!(arr, vec![11, 12, 22, 25, 34, 64, 90]);
+    }
+
+    #[test]
+    fn test_merge_slices() {
+        let left = [-3, 1, 1, 8, 20];
+        let right = [-5, 1, 2, 9];
+        let mut dest = [0; 9];
+        merge(&left, &right, &mut dest);
+        assert_eq!(dest, [-5, -3, 1, 1, 1, 2, 8, 9, 20]);
+
+        let mut only_left = [0; 3];
+        merge(&[4, 5, 6], &[], &mut only_left);
+        assert_eq!(only_left, [4, 5, 6]);
+
+        let mut empty: [i32; 0] = [];
+        merge(&[], &[], &mut empty);
+    }
+
+    #[test]
+    fn test_merge_sort_iterative_matches_recursive() {
+        let sizes = (0..=64).chain([100, 127, 128, 129, 1000, 4097, 10000]);
+        for size in sizes {
+            let data = DataGenerator::generate_random_integers(size);
+            let mut expected = data.clone();
+            let mut actual = data;
+
+            merge_sort(&mut expected);
+            merge_sort_iterative(&mut actual);
+            assert_eq!(actual, expected, "size {}", size);
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_in_place_matches_merge_sort() {
+        for size in [0, 1, 2, 3, 17, 100, 1023, 4096] {
+            for _ in 0..5 {
+                let data = DataGenerator::generate_random_integers(size);
+                let mut expected = data.clone();
+                let mut actual = data;
+                merge_sort(&mut expected);
+                merge_sort_in_place(&mut actual);
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_dc_matches_merge_sort() {
+        for size in [0, 1, 2, 999, 1001, 20_000] {
+            let data = DataGenerator::generate_random_integers(size);
+            let mut expected = data.clone();
+            expected.sort();
+
+            let mut sequential = data.clone();
+            merge_sort(&mut sequential);
+            assert_eq!(sequential, expected);
+
+            let mut actual = data.clone();
+            merge_sort_dc(&mut actual);
+            assert_eq!(actual, expected);
+
+            // Tiny base case forces deep recursion through divide/combine
+            let sorter = MergeSorter { base_len: 1 };
+            assert_eq!(dc::solve(&sorter, data.clone(), 0), expected);
+            assert_eq!(dc::solve(&sorter, data, 4), expected);
+        }
+    }
+
+    #[test]
+    fn test_count_inversions() {
+        let mut sorted = DataGenerator::generate_random_integers(1000);
+        sorted.sort_unstable();
+        assert_eq!(count_inversions(&sorted), 0);
+
+        let reversed: Vec<i32> = (0..1000).rev().collect();
+        assert_eq!(count_inversions(&reversed), 1000 * 999 / 2);
+
+        // Out-of-order pairs: (2, 1), (4, 1), (4, 3), (5, 3)
+        let small = [2, 4, 1, 5, 3];
+        assert_eq!(count_inversions(&small), 4);
+        assert_eq!(small, [2, 4, 1, 5, 3]);
+
+        // Equal elements are not inversions
+        assert_eq!(count_inversions(&[3, 3, 3]), 0);
+        assert_eq!(count_inversions(&[]), 0);
+    }
+
+    #[test]
+    fn test_sort_and_dedup() {
+        let data = DataGenerator::generate_duplicate_heavy_integers(5000, 10);
+        let unique: std::collections::HashSet<i32> = data.iter().copied().collect();
+
+        assert_eq!(count_distinct(&data), unique.len());
+
+        let mut deduped = data;
+        sort_and_dedup(&mut deduped);
+        assert_eq!(deduped.len(), unique.len());
+        assert!(deduped.windows(2).all(|pair| pair[0] < pair[1]));
+
+        assert_eq!(count_distinct(&[]), 0);
+        assert_eq!(count_distinct(&[7, 7, 7]), 1);
+    }
+
+    #[test]
+    fn test_merge_argsort_is_stable() {
+        let arr = vec![3, 1, 2, 3, 1, 0, 2];
+        let order = merge_argsort(&arr);
+        assert_eq!(order, vec![5, 1, 4, 2, 6, 0, 3]);
+
+        let data = DataGenerator::generate_duplicate_heavy_integers(2000, 8);
+        let order = merge_argsort(&data);
+        let applied: Vec<i32> = order.iter().map(|&i| data[i]).collect();
+        let mut expected = data.clone();
+        merge_sort(&mut expected);
+        assert_eq!(applied, expected);
+
+        for pair in order.windows(2) {
+            if data[pair[0]] == data[pair[1]] {
+                assert!(pair[0] < pair[1]);
+            }
+        }
+        assert!(merge_argsort(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_merge_sort_instrumented_sorts() {
+        let data = DataGenerator::generate_random_integers(5000);
+        let mut expected = data.clone();
+        merge_sort(&mut expected);
+
+        let mut actual = data;
+        let phases = merge_sort_instrumented(&mut actual);
+        assert_eq!(actual, expected);
+        assert!(phases.merge > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_merge_sort_iterative_large() {
+        let data = DataGenerator::generate_random_integers(1_000_000);
+        let mut expected = data.clone();
+        let mut actual = data;
+
+        expected.sort_unstable();
+        merge_sort_iterative(&mut actual);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_counting_sort() {
+        let inputs = vec![
+            DataGenerator::generate_random_integers(5000),
+            DataGenerator::generate_duplicate_heavy_integers(5000, 10),
+            vec![5, -3, 0, -3, 7, -1000],
+            vec![],
+        ];
+
+        for data in inputs {
+            let mut expected = data.clone();
+            let mut actual = data;
+            merge_sort(&mut expected);
+            counting_sort(&mut actual);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_counting_sort_extreme_range_falls_back() {
+        // A counter per value would be 2^32 usizes
+        let mut data = vec![i32::MAX, 0, i32::MIN, -1, i32::MAX, 1];
+        counting_sort(&mut data);
+        assert_eq!(data, vec![i32::MIN, -1, 0, 1, i32::MAX, i32::MAX]);
+    }
+
+    #[test]
+    fn test_shell_and_cycle_sort() {
+        let mut sorted = DataGenerator::generate_random_integers(3000);
+        sorted.sort_unstable();
+        let inputs = vec![
+            DataGenerator::generate_random_integers(3000),
+            sorted,
+            DataGenerator::generate_duplicate_heavy_integers(3000, 5),
+            vec![2, 1],
+            vec![],
+        ];
+
+        for data in inputs {
+            let mut expected = data.clone();
+            merge_sort(&mut expected);
+
+            let mut shell = data.clone();
+            shell_sort(&mut shell);
+            assert_eq!(shell, expected);
+
+            let mut cycle = data;
+            cycle_sort(&mut cycle);
+            assert_eq!(cycle, expected);
+        }
+    }
+
+    #[test]
+    fn test_cycle_sort_writes() {
+        let mut sorted = vec![-4, 0, 0, 3, 7, 7, 12];
+        assert_eq!(cycle_sort_counting_writes(&mut sorted), 0);
+
+        // Two 2-cycles need exactly one write per misplaced element
+        let mut arr = vec![2, 1, 4, 3];
+        assert_eq!(cycle_sort_counting_writes(&mut arr), 4);
+        assert_eq!(arr, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_bitonic_sort_non_power_of_two() {
+        for size in [0, 1, 2, 3, 5, 100, 1000, 1025, 5000] {
+            let mut data = DataGenerator::generate_random_integers(size);
+            if size > 2 {
+                data[0] = i32::MAX;
+                data[1] = i32::MIN;
+            }
+
+            let mut expected = data.clone();
+            let mut actual = data;
+            merge_sort(&mut expected);
+            bitonic_sort(&mut actual);
+            assert_eq!(actual, expected, "size {}", size);
+        }
+    }
+
+    #[test]
+    fn test_radix_sorts() {
+        let inputs = vec![
+            DataGenerator::generate_random_integers(100_000),
+            DataGenerator::generate_sorted_integers(100_000),
+            DataGenerator::generate_duplicate_heavy_integers(100_000, 7),
+            vec![-5, 3, -1000000, 42, 0, -5],
+            vec![],
+        ];
+
+        for data in inputs {
+            let mut expected = data.clone();
+            let mut sequential = data.clone();
+            let mut parallel = data;
+
+            merge_sort(&mut expected);
+            radix_sort(&mut sequential);
+            parallel_radix_sort(&mut parallel);
+            assert_eq!(sequential, expected);
+            assert_eq!(parallel, sequential);
+        }
+    }
+
+    #[test]
+    fn test_radix_sort_u64() {
+        let mut data: Vec<u64> = (0..100_000).map(|_| rand::random::<u64>()).collect();
+        data.extend([0, u64::MAX, 1, u64::MAX, 255, 256]);
+
+        let mut expected = data.clone();
+        expected.sort();
+        radix_sort_u64(&mut data);
+        assert_eq!(data, expected);
+
+        let mut empty: Vec<u64> = vec![];
+        radix_sort_u64(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_radix_sorts_full_i32_range() {
+        let mut data = DataGenerator::generate_random_integers(5000);
+        data.extend([
+            i32::MIN,
+            i32::MAX,
+            i32::MIN,
+            0,
+            -1,
+            i32::MAX - 1,
+            i32::MIN + 1,
+        ]);
+
+        let mut expected = data.clone();
+        let mut sequential = data.clone();
+        let mut parallel = data;
+
+        merge_sort(&mut expected);
+        radix_sort(&mut sequential);
+        parallel_radix_sort(&mut parallel);
+        assert_eq!(sequential, expected);
+        assert_eq!(parallel, expected);
+        assert_eq!(expected[0], i32::MIN);
+        assert_eq!(*expected.last().unwrap(), i32::MAX);
+    }
+
+    #[test]
+    fn test_bucket_sort() {
+        let data: Vec<f64> = (0..5000).map(|_| rand::random::<f64>() * 100.0).collect();
+        let mut expected = data.clone();
+        let mut actual = data;
+
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        bucket_sort(&mut actual, 64);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_bucket_sort_nan_and_infinity() {
+        let mut data = vec![3.0, f64::NAN, -1.5, 2.0, f64::NAN, 0.0];
+        bucket_sort(&mut data, 4);
+        assert_eq!(data[..4], [-1.5, 0.0, 2.0, 3.0]);
+        assert!(data[4..].iter().all(|x| x.is_nan()));
+
+        let mut mostly_nan = vec![f64::NAN, 1.0, f64::NAN];
+        bucket_sort(&mut mostly_nan, 4);
+        assert_eq!(mostly_nan[0], 1.0);
+
+        let mut infinite = vec![1.0, f64::INFINITY, -2.0, f64::NEG_INFINITY];
+        bucket_sort(&mut infinite, 4);
+        assert_eq!(infinite, vec![f64::NEG_INFINITY, -2.0, 1.0, f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_quick_sort() {
+        let mut arr = vec![64, 34, 25, 12, 22, 11, 90];
+        quick_sort(&mut arr);
+        assert_eq!(arr, vec![11, 12, 22, 25, 34, 64, 90]);
+    }
+
+    #[test]
+    fn test_quick_sort_stack_depth_is_logarithmic() {
+        // The adversary defeats median-of-three, so every partition is lopsided
+        // and the depth bound has to come from recursing on the smaller side.
+        // Recursing n deep would overflow the small stack and abort the test run
+        let n = 5000;
+        let (input, comparisons) = quick_sort_adversary(n);
+        assert!(comparisons > n * n / 8, "{} comparisons", comparisons);
+
+        let sorted = std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(move || {
+                let mut arr = input;
+                quick_sort(&mut arr);
+                arr
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert!(sorted.iter().copied().eq(0..n as i32));
+    }
+
+    #[test]
+    fn test_quick_sort_reverse_sorted_large() {
+        let mut arr: Vec<i32> = (0..1_000_000).rev().collect();
+        quick_sort(&mut arr);
+        assert!(arr.iter().copied().eq(0..1_000_000));
+    }
+
+    #[test]
+    fn test_generic_sorts_u64_and_i64() {
+        let data: Vec<u64> = DataGenerator::generate_random_integers(3000)
+            .into_iter()
+            .map(|v| (v as i64 + 2_000_000) as u64 * 1_000_000_007)
+            .collect();
+        let mut expected = data.clone();
+        expected.sort_unstable();
+
+        let mut merged = data.clone();
+        merge_sort_t(&mut merged);
+        assert_eq!(merged, expected);
+
+        let mut quick = data;
+        quick_sort_t(&mut quick);
+        assert_eq!(quick, expected);
+
+        let mut wide = vec![i64::MAX, -5, i64::MIN, 0, 3];
+        quick_sort_t(&mut wide);
+        assert_eq!(wide, vec![i64::MIN, -5, 0, 3, i64::MAX]);
+    }
+
+    #[test]
+    fn test_sort_f64_nan_last() {
+        let mut values = vec![
+            3.5,
+            f64::NAN,
+            -1.0,
+            f64::INFINITY,
+            f64::NAN,
+            0.0,
+            -f64::INFINITY,
+        ];
+        sort_f64(&mut values);
+
+        assert_eq!(
+            &values[..5],
+            &[-f64::INFINITY, -1.0, 0.0, 3.5, f64::INFINITY]
+        );
+        assert!(values[5].is_nan() && values[6].is_nan());
+
+        let mut negative_nan = vec![1.0, -f64::NAN, 0.5];
+        sort_f64(&mut negative_nan);
+        assert_eq!(&negative_nan[..2], &[0.5, 1.0]);
+        assert!(negative_nan[2].is_nan());
+    }
+
+    #[test]
+    fn test_ordered_sorts_descending() {
+        let data = DataGenerator::generate_random_integers(2000);
+        let mut ascending = data.clone();
+        merge_sort(&mut ascending);
+        let descending: Vec<i32> = ascending.iter().rev().copied().collect();
+
+        let mut merged = data.clone();
+        merge_sort_ordered(&mut merged, SortOrder::Descending);
+        assert_eq!(merged, descending);
+
+        let mut quick = data.clone();
+        quick_sort_ordered(&mut quick, SortOrder::Descending);
+        assert_eq!(quick, descending);
+
+        let mut quick_ascending = data;
+        quick_sort_ordered(&mut quick_ascending, SortOrder::Ascending);
+        assert_eq!(quick_ascending, ascending);
+    }
+
+    #[test]
+    fn test_quick_sort_ordered_sorted_and_equal_input() {
+        // Both used to nest one call per element and overflow the stack
+        let n = 100_000;
+        let sorted: Vec<i32> = (0..n).collect();
+        for order in [SortOrder::Ascending, SortOrder::Descending] {
+            let mut arr = sorted.clone();
+            quick_sort_ordered(&mut arr, order);
+            assert!(arr.windows(2).all(|pair| order.allows(&pair[0], &pair[1])));
+
+            let mut reversed: Vec<i32> = sorted.iter().rev().copied().collect();
+            quick_sort_ordered(&mut reversed, order);
+            assert_eq!(reversed, arr);
+
+            let mut equal = vec![7; n as usize];
+            quick_sort_ordered(&mut equal, order);
+            assert!(equal.iter().all(|&v| v == 7));
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_ordered_is_stable() {
+        // Ordered by key only; the tag records the original position
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct Keyed {
+            key: i32,
+            tag: usize,
+        }
+
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+
+        let keys = [3, 1, 3, 2, 1, 3, 2];
+        for order in [SortOrder::Ascending, SortOrder::Descending] {
+            let mut items: Vec<Keyed> = keys
+                .iter()
+                .enumerate()
+                .map(|(tag, &key)| Keyed { key, tag })
+                .collect();
+            merge_sort_ordered(&mut items, order);
+
+            for pair in items.windows(2) {
+                assert!(order.allows(&pair[0], &pair[1]));
+                if pair[0].key == pair[1].key {
+                    assert!(pair[0].tag < pair[1].tag, "{:?} not stable", order);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_quickselect() {
+        let data = DataGenerator::generate_random_integers(2000);
+        let mut sorted = data.clone();
+        sorted.sort_unstable();
+
+        for k in [0, 1, 500, 1000, 1999] {
+            assert_eq!(quickselect(&mut data.clone(), k), Some(sorted[k]));
+            assert_eq!(
+                quickselect_deterministic(&mut data.clone(), k),
+                Some(sorted[k])
+            );
+        }
+        assert_eq!(quickselect(&mut data.clone(), 2000), None);
+        assert_eq!(quickselect_deterministic(&mut data.clone(), 2000), None);
+    }
+
+    #[test]
+    fn test_partial_sort() {
+        let data = DataGenerator::generate_random_integers(5000);
+        let mut sorted = data.clone();
+        sorted.sort_unstable();
+
+        for k in [0, 1, 10, 2500, 4999, 5000, 6000] {
+            let mut arr = data.clone();
+            partial_sort(&mut arr, k);
+
+            let prefix = k.min(arr.len());
+            assert_eq!(arr[..prefix], sorted[..prefix]);
+            arr.sort_unstable();
+            assert_eq!(arr, sorted);
+        }
+    }
+
+    #[test]
+    fn test_quickselect_deterministic_adversarial() {
+        let data = DataGenerator::generate_reverse_sorted_integers(50000);
+
+        for k in [0, 12345, 25000, 49999] {
+            assert_eq!(
+                quickselect_deterministic(&mut data.clone(), k),
+                Some(k as i32)
+            );
+        }
+
+        let duplicates = DataGenerator::generate_duplicate_heavy_integers(50000, 3);
+        let mut sorted = duplicates.clone();
+        sorted.sort_unstable();
+        assert_eq!(
+            quickselect_deterministic(&mut duplicates.clone(), 30000),
+            Some(sorted[30000])
+        );
+    }
+
+    #[test]
+    fn test_parallel_sorts() {
+        let mut arr1 = vec![64, 34, 25, 12, 22, 11, 90];
+        let mut arr2 = arr1.clone();
+
+        parallel_merge_sort(&mut arr1);
+        parallel_quick_sort(&mut arr2);
+
+        let expected = vec![11, 12, 22, 25, 34, 64, 90];
+        assert_eq!(arr1, expected);
+        assert_eq!(arr2, expected);
+    }
+
+    #[test]
+    fn test_parallel_sorts_large() {
+        let data = DataGenerator::generate_random_integers(50_000);
+        let mut expected = data.clone();
+        expected.sort_unstable();
+
+        let mut arr1 = data.clone();
+        let mut arr2 = data;
+        parallel_merge_sort(&mut arr1);
+        parallel_quick_sort(&mut arr2);
+
+        assert_eq!(arr1, expected);
+        assert_eq!(arr2, expected);
+    }
+
+    #[test]
+    fn test_parallel_sorts_many_sizes_checked() {
+        // Debug builds validate every merged range inside the parallel merge sort
+        for size in [1001, 1024, 1500, 2049, 4097, 10_000, 33_333] {
+            let data = DataGenerator::generate_random_integers(size);
+            let mut expected = data.clone();
+            expected.sort_unstable();
+
+            let mut merged = data.clone();
+            parallel_merge_sort(&mut merged);
+            assert_eq!(merged, expected, "parallel merge sort, size {}", size);
+
+            let mut quick = data;
+            parallel_quick_sort(&mut quick);
+            assert_eq!(quick, expected, "parallel quick sort, size {}", size);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "unsorted range 10..14: index 12 (9) > index 13 (4)")]
+    fn test_check_sorted_range_reports_indices() {
+        check_sorted_range(&[1, 2, 9, 4], 10);
+    }
+
+    #[test]
+    fn test_parallel_depth_limit() {
+        for threads in [1, 2, 3, 4, 6, 8, 32, 33] {
+            let expected = (threads as f64).log2().ceil() as usize + 2;
+            assert_eq!(
+                parallel_depth_limit(threads),
+                expected,
+                "{} threads",
+                threads
+            );
+        }
+        assert_eq!(parallel_depth_limit(0), parallel_depth_limit(1));
     }
 }
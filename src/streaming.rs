@@ -0,0 +1,87 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Median of a stream of integers, maintained with two heaps
+/// The max-heap holds the lower half and the min-heap the upper half;
+/// the lower half holds at most one extra element
+#[derive(Debug, Clone, Default)]
+pub struct RunningMedian {
+    lower: BinaryHeap<i32>,
+    upper: BinaryHeap<Reverse<i32>>,
+}
+
+impl RunningMedian {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a value in O(log n)
+    pub fn insert(&mut self, x: i32) {
+        match self.lower.peek() {
+            Some(&top) if x > top => self.upper.push(Reverse(x)),
+            _ => self.lower.push(x),
+        }
+
+        // Rebalance so that len(lower) is len(upper) or len(upper) + 1
+        if self.lower.len() > self.upper.len() + 1 {
+            let moved = self.lower.pop().unwrap();
+            self.upper.push(Reverse(moved));
+        } else if self.upper.len() > self.lower.len() {
+            let Reverse(moved) = self.upper.pop().unwrap();
+            self.lower.push(moved);
+        }
+    }
+
+    /// Number of values seen so far
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+
+    /// Median of all values seen so far (mean of the middle two for even counts)
+    /// Returns NaN before the first insert
+    pub fn median(&self) -> f64 {
+        match (self.lower.peek(), self.upper.peek()) {
+            (None, _) => f64::NAN,
+            (Some(&low), Some(&Reverse(high))) if self.lower.len() == self.upper.len() => {
+                (low as f64 + high as f64) / 2.0
+            }
+            (Some(&low), _) => low as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_running_median_sequence() {
+        let values = [5, 15, 1, 3, 8, 7, 9, 10, 20, 2];
+        let expected = [5.0, 10.0, 5.0, 4.0, 5.0, 6.0, 7.0, 7.5, 8.0, 7.5];
+
+        let mut running = RunningMedian::new();
+        assert!(running.median().is_nan());
+
+        for (i, (&value, &median)) in values.iter().zip(&expected).enumerate() {
+            running.insert(value);
+            assert_eq!(running.len(), i + 1);
+            assert_eq!(running.median(), median, "after inserting {}", value);
+        }
+    }
+
+    #[test]
+    fn test_running_median_extremes() {
+        let mut running = RunningMedian::new();
+        running.insert(i32::MAX);
+        running.insert(i32::MAX);
+        assert_eq!(running.median(), i32::MAX as f64);
+
+        running.insert(i32::MIN);
+        running.insert(i32::MIN);
+        assert_eq!(running.median(), -0.5);
+    }
+}
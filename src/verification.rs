@@ -0,0 +1,184 @@
+use crate::data_generator::DataGenerator;
+use crate::geometry;
+use crate::matrix::{self, Matrix, MultiplyAlgorithm};
+use crate::sorting;
+
+type SortFn = fn(&mut [i32]);
+
+/// Largest input on which O(n²) algorithms and oracles are run
+pub const QUADRATIC_MAX: usize = 20_000;
+
+/// Outcome of checking one algorithm against its reference implementation
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationResult {
+    pub algorithm: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+impl VerificationResult {
+    fn new(algorithm: &str, passed: bool, detail: String) -> Self {
+        Self {
+            algorithm: algorithm.to_string(),
+            passed,
+            detail,
+        }
+    }
+}
+
+/// Run every algorithm against its oracle on generated data of the given size
+pub fn verify_all(size: usize) -> Vec<VerificationResult> {
+    let mut results = Vec::new();
+
+    results.extend(verify_sorting(&DataGenerator::generate_random_integers(
+        size,
+    )));
+
+    // Matrix multiplication (scaled down like the comprehensive benchmark)
+    let matrix_size = ((size as f64).sqrt() as usize).max(2);
+    let (matrix_a, matrix_b) = DataGenerator::generate_random_matrices(matrix_size);
+    for method in [MultiplyAlgorithm::Strassen, MultiplyAlgorithm::Winograd] {
+        results.push(verify_matrix_multiply(method, &matrix_a, &matrix_b));
+    }
+
+    // The brute-force oracle is quadratic, so large runs check a smaller point set
+    results.push(verify_closest_pair(&DataGenerator::generate_random_points(
+        size.clamp(2, QUADRATIC_MAX),
+    )));
+
+    results
+}
+
+/// Check each sorting algorithm against `merge_sort` as the oracle
+/// Quadratic sorts are left out above `QUADRATIC_MAX` elements
+pub fn verify_sorting(data: &[i32]) -> Vec<VerificationResult> {
+    let mut expected = data.to_vec();
+    sorting::merge_sort(&mut expected);
+
+    let mut algorithms: Vec<(&str, SortFn)> = vec![
+        ("Quick Sort", sorting::quick_sort),
+        ("Parallel Merge Sort", sorting::parallel_merge_sort),
+        ("Parallel Quick Sort", sorting::parallel_quick_sort),
+        ("Iterative Merge Sort", sorting::merge_sort_iterative),
+        ("Single-Buffer Merge Sort", sorting::merge_sort_in_place),
+        ("Framework Merge Sort", sorting::merge_sort_dc),
+        ("Shell Sort", sorting::shell_sort),
+        ("Bitonic Sort", sorting::bitonic_sort),
+        ("Counting Sort", sorting::counting_sort),
+        ("Radix Sort", sorting::radix_sort),
+        ("Parallel Radix Sort", sorting::parallel_radix_sort),
+    ];
+    if data.len() <= QUADRATIC_MAX {
+        algorithms.push(("Cycle Sort", sorting::cycle_sort));
+    }
+
+    algorithms
+        .into_iter()
+        .map(|(name, sort)| {
+            let mut actual = data.to_vec();
+            sort(&mut actual);
+
+            match actual.iter().zip(&expected).position(|(a, e)| a != e) {
+                None => VerificationResult::new(name, true, format!("{} elements", data.len())),
+                Some(index) => VerificationResult::new(
+                    name,
+                    false,
+                    format!(
+                        "mismatch at index {}: expected {}, got {}",
+                        index, expected[index], actual[index]
+                    ),
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Check a multiplication algorithm against `standard_multiply`
+pub fn verify_matrix_multiply(
+    method: MultiplyAlgorithm,
+    a: &Matrix,
+    b: &Matrix,
+) -> VerificationResult {
+    let name = format!("{} Multiplication", method.name());
+
+    let (expected, actual) = match (matrix::standard_multiply(a, b), method.multiply(a, b)) {
+        (Ok(expected), Ok(actual)) => (expected, actual),
+        (Err(e), _) | (_, Err(e)) => return VerificationResult::new(&name, false, e),
+    };
+
+    let max_error = (0..expected.rows())
+        .flat_map(|i| (0..expected.cols()).map(move |j| (i, j)))
+        .map(|(i, j)| (expected.get(i, j) - actual.get(i, j)).abs())
+        .fold(0.0, f64::max);
+
+    let passed =
+        expected.rows() == actual.rows() && expected.cols() == actual.cols() && max_error < 1e-6;
+
+    VerificationResult::new(
+        &name,
+        passed,
+        format!("{}x{}, max error {:.2e}", a.rows(), a.cols(), max_error),
+    )
+}
+
+/// Check `closest_pair_divide_conquer` against `closest_pair_brute_force`
+pub fn verify_closest_pair(points: &[geometry::Point]) -> VerificationResult {
+    const NAME: &str = "Closest Pair (Divide & Conquer)";
+
+    let expected = geometry::closest_pair_brute_force(points);
+    let actual = geometry::closest_pair_divide_conquer(points);
+
+    match (expected, actual) {
+        (None, None) => VerificationResult::new(NAME, true, "fewer than 2 points".to_string()),
+        (Some(expected), Some(actual)) => {
+            let passed = (expected.distance - actual.distance).abs() < 1e-9;
+            VerificationResult::new(
+                NAME,
+                passed,
+                format!(
+                    "expected distance {:.6}, got {:.6}",
+                    expected.distance, actual.distance
+                ),
+            )
+        }
+        _ => VerificationResult::new(NAME, false, "result presence mismatch".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_all_passes() {
+        let results = verify_all(2000);
+
+        assert!(!results.is_empty());
+        for result in &results {
+            assert!(
+                result.passed,
+                "{} failed: {}",
+                result.algorithm, result.detail
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_sorting_with_duplicates() {
+        let data = vec![3, 1, 2, 3, -5, 1, 0];
+        let results = verify_sorting(&data);
+        assert!(results.iter().all(|r| r.passed));
+    }
+
+    #[test]
+    fn test_verify_sorting_skips_quadratic_above_limit() {
+        let has_cycle_sort = |size: usize| {
+            verify_sorting(&DataGenerator::generate_random_integers(size))
+                .iter()
+                .any(|r| r.algorithm == "Cycle Sort")
+        };
+
+        assert!(has_cycle_sort(QUADRATIC_MAX));
+        assert!(!has_cycle_sort(QUADRATIC_MAX + 1));
+    }
+}
@@ -1,21 +1,281 @@
+use base64::Engine;
 use plotters::prelude::*;
-use serde_json;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 
-use crate::benchmark::BenchmarkResult;
+use crate::benchmark::{
+    analyze_complexity, estimated_memory, load_results, load_results_csv, results_to_csv,
+    BenchmarkResult, Category,
+};
+use crate::logging::status;
 
 /// Generate performance charts from benchmark results
 pub fn generate_performance_charts(
     input_file: &str,
     output_file: &str,
+    config: &ChartConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Read benchmark results from JSON file
-    let json_data = fs::read_to_string(input_file)?;
-    let results: Vec<BenchmarkResult> = serde_json::from_str(&json_data)?;
+    let results = load_results(input_file)?;
 
-    // Create the output file
-    let root = BitMapBackend::new(output_file, (1200, 800)).into_drawing_area();
+    draw_performance_charts(&results, output_file, config)?;
+    status!(
+        "Performance charts generated successfully at {}",
+        output_file
+    );
+
+    Ok(())
+}
+
+/// Generate performance charts from a CSV written by `save_results_csv`
+pub fn generate_performance_charts_from_csv(
+    input_csv: &str,
+    output_file: &str,
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let results = load_results_csv(input_csv)?;
+
+    draw_performance_charts(&results, output_file, config)?;
+    status!(
+        "Performance charts generated successfully at {}",
+        output_file
+    );
+
+    Ok(())
+}
+
+/// Series colors used when rendering charts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorScheme {
+    #[default]
+    Default,
+    /// Okabe-Ito palette, distinguishable under common color vision deficiencies
+    ColorblindSafe,
+    Grayscale,
+}
+
+const DEFAULT_PALETTE: [RGBColor; 6] = [RED, BLUE, GREEN, MAGENTA, CYAN, BLACK];
+
+const COLORBLIND_PALETTE: [RGBColor; 8] = [
+    RGBColor(230, 159, 0),
+    RGBColor(86, 180, 233),
+    RGBColor(0, 158, 115),
+    RGBColor(240, 228, 66),
+    RGBColor(0, 114, 178),
+    RGBColor(213, 94, 0),
+    RGBColor(204, 121, 167),
+    RGBColor(0, 0, 0),
+];
+
+// Stops short of white so every series stays visible on the background
+const GRAYSCALE_PALETTE: [RGBColor; 5] = [
+    RGBColor(0, 0, 0),
+    RGBColor(64, 64, 64),
+    RGBColor(112, 112, 112),
+    RGBColor(160, 160, 160),
+    RGBColor(200, 200, 200),
+];
+
+impl ColorScheme {
+    pub const ALL: [ColorScheme; 3] = [
+        ColorScheme::Default,
+        ColorScheme::ColorblindSafe,
+        ColorScheme::Grayscale,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorScheme::Default => "default",
+            ColorScheme::ColorblindSafe => "colorblind",
+            ColorScheme::Grayscale => "grayscale",
+        }
+    }
+
+    /// Colors assigned to series in order, cycling when there are more series
+    pub fn palette(&self) -> &'static [RGBColor] {
+        match self {
+            ColorScheme::Default => &DEFAULT_PALETTE,
+            ColorScheme::ColorblindSafe => &COLORBLIND_PALETTE,
+            ColorScheme::Grayscale => &GRAYSCALE_PALETTE,
+        }
+    }
+}
+
+impl std::str::FromStr for ColorScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_ascii_lowercase();
+        ColorScheme::ALL
+            .into_iter()
+            .find(|scheme| scheme.name() == lower)
+            .ok_or_else(|| {
+                let choices: Vec<&str> = ColorScheme::ALL.iter().map(|c| c.name()).collect();
+                format!(
+                    "unknown color scheme '{}' (expected one of: {})",
+                    s,
+                    choices.join(", ")
+                )
+            })
+    }
+}
+
+/// Canvas size, font sizes and colors used when rendering charts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChartConfig {
+    pub width: u32,
+    pub height: u32,
+    pub title_font_size: u32,
+    pub label_font_size: u32,
+    pub color_scheme: ColorScheme,
+}
+
+impl ChartConfig {
+    /// Config for the given canvas size, with fonts scaled from the 1200x800 defaults
+    pub fn with_size(width: u32, height: u32) -> Self {
+        let default = Self::default();
+        let scale =
+            (width as f64 / default.width as f64).min(height as f64 / default.height as f64);
+        let scaled = |size: u32| ((size as f64 * scale).round() as u32).max(8);
+
+        Self {
+            width,
+            height,
+            title_font_size: scaled(default.title_font_size),
+            label_font_size: scaled(default.label_font_size),
+            ..default
+        }
+    }
+}
+
+impl Default for ChartConfig {
+    fn default() -> Self {
+        Self {
+            width: 1200,
+            height: 800,
+            title_font_size: 30,
+            label_font_size: 20,
+            color_scheme: ColorScheme::Default,
+        }
+    }
+}
+
+/// Artifact kinds that can be regenerated from a results JSON file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Png,
+    Svg,
+    Report,
+    Csv,
+    Html,
+}
+
+impl OutputFormat {
+    pub const ALL: [OutputFormat; 5] = [
+        OutputFormat::Png,
+        OutputFormat::Svg,
+        OutputFormat::Report,
+        OutputFormat::Csv,
+        OutputFormat::Html,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Png => "png",
+            OutputFormat::Svg => "svg",
+            OutputFormat::Report => "report",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Html => "html",
+        }
+    }
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_ascii_lowercase();
+        OutputFormat::ALL
+            .into_iter()
+            .find(|format| format.name() == lower)
+            .ok_or_else(|| {
+                let choices: Vec<&str> = OutputFormat::ALL.iter().map(|f| f.name()).collect();
+                format!(
+                    "unknown format '{}' (expected one of: {})",
+                    s,
+                    choices.join(", ")
+                )
+            })
+    }
+}
+
+/// Regenerate the artifact of the given format from a results JSON file
+pub fn generate_visualization(
+    input_file: &str,
+    output_file: &str,
+    format: OutputFormat,
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let results = load_results(input_file)?;
+
+    match format {
+        OutputFormat::Png => draw_performance_charts(&results, output_file, config),
+        OutputFormat::Svg => draw_performance_charts_svg(&results, output_file, config),
+        OutputFormat::Report => generate_performance_report(&results, output_file),
+        OutputFormat::Csv => generate_csv_summary(&results, output_file),
+        OutputFormat::Html => generate_html_report(&results, output_file, config),
+    }
+}
+
+/// Render all performance charts for the given results into a PNG file
+fn draw_performance_charts(
+    results: &[BenchmarkResult],
+    output_file: &str,
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(output_file, (config.width, config.height)).into_drawing_area();
+    draw_chart_layout(root, results, config)
+}
+
+/// Render all performance charts into an in-memory PNG image
+fn draw_performance_charts_png_bytes(
+    results: &[BenchmarkResult],
+    config: &ChartConfig,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut pixels = vec![0u8; config.width as usize * config.height as usize * 3];
+    {
+        let root = BitMapBackend::with_buffer(&mut pixels, (config.width, config.height))
+            .into_drawing_area();
+        draw_chart_layout(root, results, config)?;
+    }
+
+    let mut png_bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png_bytes, config.width, config.height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+    Ok(png_bytes)
+}
+
+/// Render all performance charts for the given results into an SVG file
+fn draw_performance_charts_svg(
+    results: &[BenchmarkResult],
+    output_file: &str,
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let root = SVGBackend::new(output_file, (config.width, config.height)).into_drawing_area();
+    draw_chart_layout(root, results, config)
+}
+
+/// Lay out the four performance charts on any plotters backend
+fn draw_chart_layout<DB: DrawingBackend>(
+    root: DrawingArea<DB, plotters::coord::Shift>,
+    results: &[BenchmarkResult],
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
     root.fill(&WHITE)?;
 
     // Split the drawing area into multiple charts
@@ -27,30 +287,40 @@ pub fn generate_performance_charts(
     let execution_chart = &upper_areas[0];
     let memory_chart = &upper_areas[1];
 
+    let lower_areas = lower.split_evenly((1, 2));
+    let comparison_chart = &lower_areas[0];
+    let boxplot_chart = &lower_areas[1];
+
     // Generate execution time chart
-    draw_execution_time_chart(execution_chart.clone(), &results)?;
+    draw_execution_time_chart(execution_chart.clone(), results, config)?;
 
     // Generate memory usage chart
-    draw_memory_usage_chart(memory_chart.clone(), &results)?;
+    draw_memory_usage_chart(memory_chart.clone(), results, config)?;
 
     // Generate algorithm comparison chart
-    draw_algorithm_comparison_chart(lower.clone(), &results)?;
+    draw_algorithm_comparison_chart(comparison_chart.clone(), results, config)?;
+
+    // Generate per-run distribution chart
+    draw_boxplot_chart(boxplot_chart.clone(), results, config)?;
 
     root.present()?;
-    println!(
-        "Performance charts generated successfully at {}",
-        output_file
-    );
 
     Ok(())
 }
 
-fn draw_execution_time_chart(
-    drawing_area: DrawingArea<BitMapBackend, plotters::coord::Shift>,
+fn draw_execution_time_chart<DB: DrawingBackend>(
+    drawing_area: DrawingArea<DB, plotters::coord::Shift>,
     results: &[BenchmarkResult],
-) -> Result<(), Box<dyn std::error::Error>> {
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
     let mut chart = ChartBuilder::on(&drawing_area)
-        .caption("Execution Time vs Data Size", ("sans-serif", 30))
+        .caption(
+            "Execution Time vs Data Size",
+            ("sans-serif", config.title_font_size),
+        )
         .margin(5)
         .x_label_area_size(40)
         .y_label_area_size(50)
@@ -75,27 +345,27 @@ fn draw_execution_time_chart(
         let time_ms = result.execution_time.as_secs_f64() * 1000.0;
         algorithm_data
             .entry(result.algorithm_name.clone())
-            .or_insert_with(Vec::new)
+            .or_default()
             .push((result.data_size, time_ms));
     }
 
-    let colors = [&RED, &BLUE, &GREEN, &MAGENTA, &CYAN, &BLACK];
-    let mut color_idx = 0;
+    let palette = config.color_scheme.palette();
 
-    for (algorithm, data) in algorithm_data.iter_mut() {
+    for (color_idx, (algorithm, data)) in algorithm_data.iter_mut().enumerate() {
         data.sort_by_key(|&(size, _)| size);
 
         chart
             .draw_series(LineSeries::new(
                 data.iter().cloned(),
-                colors[color_idx % colors.len()],
+                palette[color_idx % palette.len()],
             ))?
             .label(algorithm)
             .legend(move |(x, y)| {
-                PathElement::new(vec![(x, y), (x + 10, y)], colors[color_idx % colors.len()])
+                PathElement::new(
+                    vec![(x, y), (x + 10, y)],
+                    palette[color_idx % palette.len()],
+                )
             });
-
-        color_idx += 1;
     }
 
     chart.configure_series_labels().draw()?;
@@ -103,24 +373,31 @@ fn draw_execution_time_chart(
     Ok(())
 }
 
-fn draw_memory_usage_chart(
-    drawing_area: DrawingArea<BitMapBackend, plotters::coord::Shift>,
+fn draw_memory_usage_chart<DB: DrawingBackend>(
+    drawing_area: DrawingArea<DB, plotters::coord::Shift>,
     results: &[BenchmarkResult],
-) -> Result<(), Box<dyn std::error::Error>> {
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
     // Filter results that have memory usage data
     let memory_results: Vec<_> = results.iter().filter(|r| r.memory_used.is_some()).collect();
 
     if memory_results.is_empty() {
         // Draw empty chart with message
         let mut chart = ChartBuilder::on(&drawing_area)
-            .caption("Memory Usage (No Data Available)", ("sans-serif", 30))
+            .caption(
+                "Memory Usage (No Data Available)",
+                ("sans-serif", config.title_font_size),
+            )
             .margin(5)
             .build_cartesian_2d(0..1, 0..1)?;
 
         chart.draw_series(std::iter::once(Text::new(
             "No memory usage data available",
             (0, 0),
-            ("sans-serif", 20),
+            ("sans-serif", config.label_font_size),
         )))?;
         return Ok(());
     }
@@ -131,7 +408,10 @@ fn draw_memory_usage_chart(
         .fold(0.0, f64::max);
 
     let mut chart = ChartBuilder::on(&drawing_area)
-        .caption("Memory Usage vs Data Size", ("sans-serif", 30))
+        .caption(
+            "Memory Usage vs Data Size",
+            ("sans-serif", config.title_font_size),
+        )
         .margin(5)
         .x_label_area_size(40)
         .y_label_area_size(50)
@@ -159,28 +439,28 @@ fn draw_memory_usage_chart(
             let memory_mb = memory as f64 / 1024.0 / 1024.0;
             algorithm_memory
                 .entry(result.algorithm_name.clone())
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push((result.data_size, memory_mb));
         }
     }
 
-    let colors = [&RED, &BLUE, &GREEN, &MAGENTA, &CYAN, &BLACK];
-    let mut color_idx = 0;
+    let palette = config.color_scheme.palette();
 
-    for (algorithm, data) in algorithm_memory.iter_mut() {
+    for (color_idx, (algorithm, data)) in algorithm_memory.iter_mut().enumerate() {
         data.sort_by_key(|&(size, _)| size);
 
         chart
             .draw_series(LineSeries::new(
                 data.iter().cloned(),
-                colors[color_idx % colors.len()],
+                palette[color_idx % palette.len()],
             ))?
             .label(algorithm)
             .legend(move |(x, y)| {
-                PathElement::new(vec![(x, y), (x + 10, y)], colors[color_idx % colors.len()])
+                PathElement::new(
+                    vec![(x, y), (x + 10, y)],
+                    palette[color_idx % palette.len()],
+                )
             });
-
-        color_idx += 1;
     }
 
     chart.configure_series_labels().draw()?;
@@ -188,17 +468,21 @@ fn draw_memory_usage_chart(
     Ok(())
 }
 
-fn draw_algorithm_comparison_chart(
-    drawing_area: DrawingArea<BitMapBackend, plotters::coord::Shift>,
+fn draw_algorithm_comparison_chart<DB: DrawingBackend>(
+    drawing_area: DrawingArea<DB, plotters::coord::Shift>,
     results: &[BenchmarkResult],
-) -> Result<(), Box<dyn std::error::Error>> {
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
     // Group results by data size and find common sizes
     let mut size_groups: HashMap<usize, Vec<&BenchmarkResult>> = HashMap::new();
 
     for result in results {
         size_groups
             .entry(result.data_size)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(result);
     }
 
@@ -220,7 +504,7 @@ fn draw_algorithm_comparison_chart(
         let mut chart = ChartBuilder::on(&drawing_area)
             .caption(
                 "Algorithm Comparison (No Data Available)",
-                ("sans-serif", 30),
+                ("sans-serif", config.title_font_size),
             )
             .margin(5)
             .build_cartesian_2d(0..1, 0..1)?;
@@ -228,7 +512,7 @@ fn draw_algorithm_comparison_chart(
         chart.draw_series(std::iter::once(Text::new(
             "No comparison data available",
             (0, 0),
-            ("sans-serif", 20),
+            ("sans-serif", config.label_font_size),
         )))?;
         return Ok(());
     }
@@ -240,8 +524,8 @@ fn draw_algorithm_comparison_chart(
 
     let mut chart = ChartBuilder::on(&drawing_area)
         .caption(
-            &format!("Algorithm Comparison (Data Size: {})", comparison_size),
-            ("sans-serif", 30),
+            format!("Algorithm Comparison (Data Size: {})", comparison_size),
+            ("sans-serif", config.title_font_size),
         )
         .margin(5)
         .x_label_area_size(60)
@@ -263,7 +547,12 @@ fn draw_algorithm_comparison_chart(
     // Draw bars for each algorithm
     for (i, result) in comparison_results.iter().enumerate() {
         let time_ms = result.execution_time.as_secs_f64() * 1000.0;
-        let color = if result.parallel { BLUE } else { RED };
+        let palette = config.color_scheme.palette();
+        let color = if result.parallel {
+            palette[1]
+        } else {
+            palette[0]
+        };
 
         // Create rectangles as individual elements
         chart
@@ -279,6 +568,351 @@ fn draw_algorithm_comparison_chart(
     Ok(())
 }
 
+/// Draw a box-and-whisker plot of per-run durations for each algorithm and data size
+fn draw_boxplot_chart<DB: DrawingBackend>(
+    drawing_area: DrawingArea<DB, plotters::coord::Shift>,
+    results: &[BenchmarkResult],
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    // Collect run samples per (algorithm, size), keeping first-seen order
+    let mut groups: Vec<(String, usize)> = Vec::new();
+    let mut samples: HashMap<(String, usize), Vec<std::time::Duration>> = HashMap::new();
+
+    for result in results.iter().filter(|r| !r.run_times.is_empty()) {
+        let key = (result.algorithm_name.clone(), result.data_size);
+        if !samples.contains_key(&key) {
+            groups.push(key.clone());
+        }
+        samples
+            .entry(key)
+            .or_default()
+            .extend(result.run_times.iter().copied());
+    }
+
+    if groups.is_empty() {
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .caption(
+                "Run Distribution (No Data Available)",
+                ("sans-serif", config.title_font_size),
+            )
+            .margin(5)
+            .build_cartesian_2d(0..1, 0..1)?;
+
+        chart.draw_series(std::iter::once(Text::new(
+            "No per-run data available",
+            (0, 0),
+            ("sans-serif", config.label_font_size),
+        )))?;
+        return Ok(());
+    }
+
+    let summaries: Vec<[f32; 5]> = groups
+        .iter()
+        .map(|key| {
+            let sorted = samples.get_mut(key).unwrap();
+            sorted.sort();
+            five_number_summary(sorted)
+        })
+        .collect();
+
+    let max_time = summaries
+        .iter()
+        .map(|s| s[4])
+        .fold(0.0f32, f32::max)
+        .max(f32::EPSILON);
+
+    // Each group gets a ten-unit slot labeled at its center, so the box and
+    // whisker edges fall on integer x coordinates
+    const SLOT: u32 = 10;
+    let centers: Vec<u32> = (0..groups.len() as u32)
+        .map(|i| i * SLOT + SLOT / 2)
+        .collect();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .caption(
+            "Run Time Distribution",
+            ("sans-serif", config.title_font_size),
+        )
+        .margin(5)
+        .x_label_area_size(60)
+        .y_label_area_size(50)
+        .build_cartesian_2d(
+            (0..groups.len() as u32 * SLOT).with_key_points(centers.clone()),
+            0f32..max_time * 1.1,
+        )?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Algorithm (size)")
+        .y_desc("Execution Time (ms)")
+        .x_label_formatter(&|x| {
+            groups
+                .get((x / SLOT) as usize)
+                .map(|(name, size)| format!("{} ({})", name, size))
+                .unwrap_or_default()
+        })
+        .draw()?;
+
+    // Whiskers run to the observed min and max, not to 1.5 IQR fences;
+    // every size of one algorithm shares its color
+    let palette = config.color_scheme.palette();
+    let mut algorithms: Vec<&str> = Vec::new();
+    for ((name, _), (&center, summary)) in groups.iter().zip(centers.iter().zip(&summaries)) {
+        let index = match algorithms.iter().position(|a| a == name) {
+            Some(index) => index,
+            None => {
+                algorithms.push(name);
+                algorithms.len() - 1
+            }
+        };
+        let color = palette[index % palette.len()];
+        let [min, q1, median, q3, max] = *summary;
+        let (left, right) = (center - 2, center + 2);
+        let (cap_left, cap_right) = (center - 1, center + 1);
+
+        chart.draw_series(std::iter::once(Rectangle::new(
+            [(left, q3), (right, q1)],
+            color.stroke_width(2),
+        )))?;
+        chart.draw_series(
+            [
+                vec![(left, median), (right, median)],
+                vec![(center, q3), (center, max)],
+                vec![(center, q1), (center, min)],
+                vec![(cap_left, max), (cap_right, max)],
+                vec![(cap_left, min), (cap_right, min)],
+            ]
+            .into_iter()
+            .map(|points| PathElement::new(points, color.stroke_width(2))),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Min, lower quartile, median, upper quartile and max in milliseconds
+/// `sorted` must be ascending and non-empty
+fn five_number_summary(sorted: &[std::time::Duration]) -> [f32; 5] {
+    let ms = |d: std::time::Duration| (d.as_secs_f64() * 1000.0) as f32;
+    [
+        ms(sorted[0]),
+        ms(percentile(sorted, 25.0)),
+        ms(percentile(sorted, 50.0)),
+        ms(percentile(sorted, 75.0)),
+        ms(sorted[sorted.len() - 1]),
+    ]
+}
+
+/// Draw a grid of algorithms by data size, shaded by execution time
+/// Each column is normalized to its slowest algorithm; missing combinations stay blank
+pub fn draw_heatmap_chart<DB: DrawingBackend>(
+    drawing_area: DrawingArea<DB, plotters::coord::Shift>,
+    results: &[BenchmarkResult],
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    let mut algorithms: Vec<String> = Vec::new();
+    let mut sizes: Vec<usize> = Vec::new();
+    let mut times: HashMap<(String, usize), f64> = HashMap::new();
+
+    for result in results {
+        if !algorithms.contains(&result.algorithm_name) {
+            algorithms.push(result.algorithm_name.clone());
+        }
+        if !sizes.contains(&result.data_size) {
+            sizes.push(result.data_size);
+        }
+        times.insert(
+            (result.algorithm_name.clone(), result.data_size),
+            result.execution_time.as_secs_f64() * 1000.0,
+        );
+    }
+    sizes.sort_unstable();
+
+    if algorithms.is_empty() {
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .caption(
+                "Execution Time Heatmap (No Data Available)",
+                ("sans-serif", config.title_font_size),
+            )
+            .margin(5)
+            .build_cartesian_2d(0..1, 0..1)?;
+
+        chart.draw_series(std::iter::once(Text::new(
+            "No benchmark data available",
+            (0, 0),
+            ("sans-serif", config.label_font_size),
+        )))?;
+        return Ok(());
+    }
+
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .caption(
+            "Execution Time Heatmap",
+            ("sans-serif", config.title_font_size),
+        )
+        .margin(5)
+        .x_label_area_size(40)
+        .y_label_area_size(160)
+        .build_cartesian_2d(
+            (0..sizes.len()).into_segmented(),
+            (0..algorithms.len()).into_segmented(),
+        )?;
+
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .x_desc("Data Size")
+        .x_labels(sizes.len())
+        .y_labels(algorithms.len())
+        .x_label_formatter(&|x| match x {
+            SegmentValue::CenterOf(i) => sizes.get(*i).map_or(String::new(), |s| s.to_string()),
+            _ => String::new(),
+        })
+        .y_label_formatter(&|y| match y {
+            SegmentValue::CenterOf(i) => algorithms.get(*i).cloned().unwrap_or_default(),
+            _ => String::new(),
+        })
+        .draw()?;
+
+    for (column, &size) in sizes.iter().enumerate() {
+        let column_max = algorithms
+            .iter()
+            .filter_map(|algorithm| times.get(&(algorithm.clone(), size)))
+            .fold(0.0, |max: f64, &t| max.max(t))
+            .max(f64::EPSILON);
+
+        chart.draw_series(
+            algorithms
+                .iter()
+                .enumerate()
+                .filter_map(|(row, algorithm)| {
+                    let time = times.get(&(algorithm.clone(), size))?;
+                    let shade = (255.0 * (1.0 - time / column_max)) as u8;
+                    Some(Rectangle::new(
+                        [
+                            (SegmentValue::Exact(column), SegmentValue::Exact(row)),
+                            (
+                                SegmentValue::Exact(column + 1),
+                                SegmentValue::Exact(row + 1),
+                            ),
+                        ],
+                        RGBColor(255, shade, shade).filled(),
+                    ))
+                }),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Render the matrix algorithm comparison chart into a PNG file
+pub fn generate_matrix_comparison_chart(
+    results: &[BenchmarkResult],
+    output_file: &str,
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(output_file, (config.width, config.height)).into_drawing_area();
+    root.fill(&WHITE)?;
+    draw_matrix_comparison_chart(root.clone(), results, config)?;
+    root.present()?;
+    Ok(())
+}
+
+/// Plot execution time against matrix size with one line per multiplication algorithm
+/// Only matrix results are used; timed-out runs are skipped so their capped time
+/// does not look like a measurement
+pub fn draw_matrix_comparison_chart<DB: DrawingBackend>(
+    drawing_area: DrawingArea<DB, plotters::coord::Shift>,
+    results: &[BenchmarkResult],
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    let mut series: BTreeMap<&str, Vec<(usize, f64)>> = BTreeMap::new();
+    for result in results
+        .iter()
+        .filter(|r| r.category == Category::Matrix && !r.timed_out)
+    {
+        series
+            .entry(result.algorithm_name.as_str())
+            .or_default()
+            .push((
+                result.data_size,
+                result.execution_time.as_secs_f64() * 1000.0,
+            ));
+    }
+
+    if series.is_empty() {
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .caption(
+                "Matrix Multiplication (No Data Available)",
+                ("sans-serif", config.title_font_size),
+            )
+            .margin(5)
+            .build_cartesian_2d(0..1, 0..1)?;
+
+        chart.draw_series(std::iter::once(Text::new(
+            "No matrix benchmark data available",
+            (0, 0),
+            ("sans-serif", config.label_font_size),
+        )))?;
+        return Ok(());
+    }
+
+    let points = series.values().flatten();
+    let max_size = points.clone().map(|&(size, _)| size).max().unwrap_or(1);
+    let max_time = points
+        .map(|&(_, ms)| ms)
+        .fold(0.0, f64::max)
+        .max(f64::EPSILON);
+
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .caption(
+            "Matrix Multiplication: Time vs Size",
+            ("sans-serif", config.title_font_size),
+        )
+        .margin(5)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0usize..max_size, 0f64..max_time * 1.05)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Matrix Size (N)")
+        .y_desc("Execution Time (ms)")
+        .draw()?;
+
+    let palette = config.color_scheme.palette();
+
+    for (color_idx, (algorithm, data)) in series.iter_mut().enumerate() {
+        data.sort_by_key(|&(size, _)| size);
+        let color = palette[color_idx % palette.len()];
+
+        chart
+            .draw_series(LineSeries::new(data.iter().cloned(), color))?
+            .label(*algorithm)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], color));
+        chart.draw_series(
+            data.iter()
+                .map(|&point| Circle::new(point, 3, color.filled())),
+        )?;
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
 /// Generate detailed performance report
 pub fn generate_performance_report(
     results: &[BenchmarkResult],
@@ -328,25 +962,212 @@ pub fn generate_performance_report(
         ));
     }
 
+    // Results grouped by problem domain
+    let mut by_category: BTreeMap<Category, Vec<&BenchmarkResult>> = BTreeMap::new();
+    for result in results {
+        by_category.entry(result.category).or_default().push(result);
+    }
+
+    report.push_str("\n## Results by Category\n");
+    for (category, category_results) in &by_category {
+        report.push_str(&format!("\n### {}\n\n", category.name()));
+        for result in category_results {
+            report.push_str(&format!(
+                "- {} ({} elements): {:.2}ms\n",
+                result.algorithm_name,
+                result.data_size,
+                result.execution_time.as_secs_f64() * 1000.0
+            ));
+        }
+    }
+
+    // Tail latency from the per-run samples
+    let mut with_samples: Vec<&BenchmarkResult> =
+        results.iter().filter(|r| !r.run_times.is_empty()).collect();
+    if !with_samples.is_empty() {
+        with_samples.sort_by(|a, b| {
+            a.algorithm_name
+                .cmp(&b.algorithm_name)
+                .then(a.data_size.cmp(&b.data_size))
+        });
+
+        report.push_str("\n## Latency Percentiles\n\n");
+        for result in with_samples {
+            let mut samples = result.run_times.clone();
+            samples.sort();
+            report.push_str(&format!(
+                "- {} ({} elements): p50 {:.2}ms, p90 {:.2}ms, p99 {:.2}ms\n",
+                result.algorithm_name,
+                result.data_size,
+                percentile(&samples, 50.0).as_secs_f64() * 1000.0,
+                percentile(&samples, 90.0).as_secs_f64() * 1000.0,
+                percentile(&samples, 99.0).as_secs_f64() * 1000.0
+            ));
+        }
+    }
+
+    // RSS deltas are noisy, so show the analytic estimate next to them
+    if !results.is_empty() {
+        report.push_str("\n## Memory: Measured vs Estimated\n\n");
+        for result in results {
+            let megabytes = |bytes: Option<usize>| {
+                bytes.map_or("N/A".to_string(), |b| {
+                    format!("{:.3}MB", b as f64 / 1024.0 / 1024.0)
+                })
+            };
+            report.push_str(&format!(
+                "- {} ({} elements): measured {}, estimated {}\n",
+                result.algorithm_name,
+                result.data_size,
+                megabytes(result.memory_used),
+                megabytes(estimated_memory(result))
+            ));
+        }
+    }
+
+    // Fitted growth exponent per algorithm; timed-out runs only give a lower bound
+    let mut timings: BTreeMap<&str, Vec<(usize, f64)>> = BTreeMap::new();
+    for result in results.iter().filter(|r| !r.timed_out) {
+        timings
+            .entry(&result.algorithm_name)
+            .or_default()
+            .push((result.data_size, result.execution_time.as_secs_f64()));
+    }
+    let exponents: Vec<(&str, f64, usize)> = timings
+        .iter()
+        .map(|(name, points)| (*name, analyze_complexity(points), points.len()))
+        .filter(|(_, exponent, _)| exponent.is_finite())
+        .collect();
+    if !exponents.is_empty() {
+        report.push_str("\n## Empirical Complexity\n\n");
+        for (name, exponent, count) in exponents {
+            report.push_str(&format!(
+                "- {}: time grows as n^{:.2} (fitted over {} results)\n",
+                name, exponent, count
+            ));
+        }
+    }
+
+    if !results.is_empty() {
+        report.push_str("\n## Comparison Table\n\n");
+        report.push_str(&generate_comparison_table(results));
+    }
+
     // Write report to file
     fs::write(output_file, report)?;
-    println!("Performance report generated at {}", output_file);
+    status!("Performance report generated at {}", output_file);
 
     Ok(())
 }
 
-/// Generate CSV summary for further analysis
-pub fn generate_csv_summary(
+/// GitHub-flavored Markdown table of the results, sorted by size then algorithm
+/// Median comes from the per-run samples, falling back to the mean when there are none
+pub fn generate_comparison_table(results: &[BenchmarkResult]) -> String {
+    let mut sorted: Vec<&BenchmarkResult> = results.iter().collect();
+    sorted.sort_by(|a, b| {
+        a.data_size
+            .cmp(&b.data_size)
+            .then_with(|| a.algorithm_name.cmp(&b.algorithm_name))
+    });
+
+    let mut table =
+        String::from("| Algorithm | Size | Mean(ms) | Median(ms) | Memory(MB) | Parallel |\n");
+    table.push_str("|---|---:|---:|---:|---:|:---:|\n");
+
+    for result in sorted {
+        let median = if result.run_times.is_empty() {
+            result.execution_time
+        } else {
+            let mut samples = result.run_times.clone();
+            samples.sort();
+            percentile(&samples, 50.0)
+        };
+
+        table.push_str(&format!(
+            "| {} | {} | {:.3} | {:.3} | {} | {} |\n",
+            result.algorithm_name,
+            result.data_size,
+            result.execution_time.as_secs_f64() * 1000.0,
+            median.as_secs_f64() * 1000.0,
+            result.memory_used.map_or("N/A".to_string(), |m| format!(
+                "{:.2}",
+                m as f64 / 1024.0 / 1024.0
+            )),
+            if result.parallel { "yes" } else { "no" }
+        ));
+    }
+
+    table
+}
+
+/// Nearest-rank percentile of an ascending, non-empty sample list
+fn percentile(sorted: &[std::time::Duration], p: f64) -> std::time::Duration {
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Generate a self-contained HTML report with the performance chart embedded
+pub fn generate_html_report(
     results: &[BenchmarkResult],
     output_file: &str,
+    config: &ChartConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut csv_content =
-        String::from("Algorithm,DataSize,ExecutionTime(ms),MemoryUsed(MB),Parallel\n");
+    // Render the chart in memory so it can be inlined as a data URI
+    let chart_bytes = draw_performance_charts_png_bytes(results, config)?;
+    let chart_base64 = base64::engine::general_purpose::STANDARD.encode(chart_bytes);
+
+    let mut html = String::new();
+
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Performance Analysis Report</title>\n");
+    html.push_str("<style>\n");
+    html.push_str("body { font-family: sans-serif; margin: 2em; }\n");
+    html.push_str("table { border-collapse: collapse; margin-bottom: 1.5em; }\n");
+    html.push_str("th, td { border: 1px solid #999; padding: 4px 10px; text-align: right; }\n");
+    html.push_str("th { background: #eee; }\n");
+    html.push_str("</style>\n</head>\n<body>\n");
+    html.push_str("<h1>Performance Analysis Report</h1>\n");
+
+    // Summary statistics
+    let unique_algorithms: std::collections::HashSet<_> =
+        results.iter().map(|r| &r.algorithm_name).collect();
+    let mut data_sizes: Vec<_> = results
+        .iter()
+        .map(|r| r.data_size)
+        .collect::<std::collections::HashSet<_>>()
+        .into_iter()
+        .collect();
+    data_sizes.sort();
+
+    html.push_str("<h2>Summary Statistics</h2>\n<table>\n");
+    html.push_str(&format!(
+        "<tr><th>Total benchmarks</th><td>{}</td></tr>\n",
+        results.len()
+    ));
+    html.push_str(&format!(
+        "<tr><th>Unique algorithms</th><td>{}</td></tr>\n",
+        unique_algorithms.len()
+    ));
+    html.push_str(&format!(
+        "<tr><th>Data sizes tested</th><td>{}</td></tr>\n",
+        data_sizes
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    html.push_str("</table>\n");
 
+    // Per-result table
+    html.push_str("<h2>Benchmark Results</h2>\n<table>\n");
+    html.push_str(
+        "<tr><th>Algorithm</th><th>Data Size</th><th>Execution Time (ms)</th>\
+         <th>Memory Used (MB)</th><th>Parallel</th></tr>\n",
+    );
     for result in results {
-        csv_content.push_str(&format!(
-            "{},{},{:.3},{},{}\n",
-            result.algorithm_name,
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{:.3}</td><td>{}</td><td>{}</td></tr>\n",
+            escape_html(&result.algorithm_name),
             result.data_size,
             result.execution_time.as_secs_f64() * 1000.0,
             result.memory_used.map_or("N/A".to_string(), |m| format!(
@@ -356,7 +1177,383 @@ pub fn generate_csv_summary(
             result.parallel
         ));
     }
+    html.push_str("</table>\n");
+
+    // Charts
+    html.push_str("<h2>Charts</h2>\n");
+    html.push_str(&format!(
+        "<img alt=\"Performance charts\" src=\"data:image/png;base64,{}\">\n",
+        chart_base64
+    ));
+
+    html.push_str("</body>\n</html>\n");
+
+    fs::write(output_file, html)?;
+    status!("HTML report generated at {}", output_file);
+
+    Ok(())
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
 
-    fs::write(output_file, csv_content)?;
+/// Generate CSV summary for further analysis
+pub fn generate_csv_summary(
+    results: &[BenchmarkResult],
+    output_file: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    fs::write(output_file, results_to_csv(results))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn sample_result(name: &str, size: usize, runs_ms: &[u64]) -> BenchmarkResult {
+        let run_times: Vec<Duration> = runs_ms
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        BenchmarkResult {
+            algorithm_name: name.to_string(),
+            data_size: size,
+            execution_time: run_times.iter().sum::<Duration>() / run_times.len() as u32,
+            memory_used: None,
+            parallel: false,
+            run_times,
+            timed_out: false,
+            output_count: None,
+            category: Category::Sorting,
+        }
+    }
+
+    #[test]
+    fn test_csv_summary_has_category_column() {
+        let results = vec![sample_result("Merge Sort", 1000, &[5, 7, 6])];
+        let path = std::env::temp_dir().join("dcp_test_summary.csv");
+        generate_csv_summary(&results, &path.to_string_lossy()).unwrap();
+        let csv = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].ends_with(",Category"));
+        assert_eq!(lines[1], "Merge Sort,1000,6.000,N/A,false,Sorting");
+    }
+
+    #[test]
+    fn test_comparison_table() {
+        let results = vec![
+            sample_result("Quick Sort", 5000, &[20, 22, 25]),
+            sample_result("Merge Sort", 5000, &[21, 23, 40]),
+            sample_result("Quick Sort", 1000, &[3, 4, 8]),
+        ];
+
+        let table = generate_comparison_table(&results);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "| Algorithm | Size | Mean(ms) | Median(ms) | Memory(MB) | Parallel |"
+        );
+        assert_eq!(lines.len(), 2 + results.len());
+        assert_eq!(lines[2], "| Quick Sort | 1000 | 5.000 | 4.000 | N/A | no |");
+        assert!(lines[3].starts_with("| Merge Sort | 5000 |"));
+        assert!(lines[4].starts_with("| Quick Sort | 5000 |"));
+    }
+
+    #[test]
+    fn test_boxplot_chart_renders() {
+        let results = vec![
+            sample_result("Merge Sort", 1000, &[5, 7, 6, 9, 5]),
+            sample_result("Quick Sort", 1000, &[3, 4, 8, 4, 3]),
+            sample_result("Merge Sort", 5000, &[20, 22, 25, 21, 30]),
+        ];
+
+        let path = std::env::temp_dir().join("dcp_test_boxplot.png");
+        {
+            let root = BitMapBackend::new(&path, (800, 600)).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            draw_boxplot_chart(root.clone(), &results, &ChartConfig::default()).unwrap();
+            root.present().unwrap();
+        }
+
+        assert!(fs::metadata(&path).unwrap().len() > 0);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_five_number_summary_uses_observed_extremes() {
+        let mut samples: Vec<Duration> = [40, 1, 2, 3, 4, 5, 6, 7]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        samples.sort();
+
+        // A 1.5 IQR fence would put the lower whisker below zero and cut off 40ms
+        assert_eq!(five_number_summary(&samples), [1.0, 2.0, 4.0, 6.0, 40.0]);
+    }
+
+    #[test]
+    fn test_generate_visualization_all_formats() {
+        let results = vec![
+            sample_result("Merge Sort", 1000, &[5, 7, 6]),
+            sample_result("Quick Sort", 1000, &[3, 4, 8]),
+            sample_result("Merge Sort", 5000, &[20, 22, 25]),
+        ];
+        let input = std::env::temp_dir().join("dcp_test_visualize_input.json");
+        fs::write(&input, serde_json::to_string(&results).unwrap()).unwrap();
+
+        for format in OutputFormat::ALL {
+            let output = std::env::temp_dir().join(format!("dcp_test_visualize.{}", format.name()));
+            generate_visualization(
+                &input.to_string_lossy(),
+                &output.to_string_lossy(),
+                format,
+                &ChartConfig::default(),
+            )
+            .unwrap();
+
+            assert!(
+                fs::metadata(&output).unwrap().len() > 0,
+                "{} output is empty",
+                format.name()
+            );
+            let _ = fs::remove_file(&output);
+        }
+        let _ = fs::remove_file(&input);
+    }
+
+    #[test]
+    fn test_chart_config_custom_sizes() {
+        let results = vec![
+            sample_result("Merge Sort", 1000, &[5, 7, 6]),
+            sample_result("Quick Sort", 1000, &[3, 4, 8]),
+            sample_result("Merge Sort", 5000, &[20, 22, 25]),
+        ];
+
+        for (width, height) in [(1600, 900), (400, 300)] {
+            let config = ChartConfig::with_size(width, height);
+            let path =
+                std::env::temp_dir().join(format!("dcp_test_chart_{}x{}.png", width, height));
+            draw_performance_charts(&results, &path.to_string_lossy(), &config).unwrap();
+
+            assert!(fs::metadata(&path).unwrap().len() > 0);
+            let _ = fs::remove_file(&path);
+        }
+
+        let small = ChartConfig::with_size(400, 300);
+        assert!(small.title_font_size < ChartConfig::default().title_font_size);
+    }
+
+    #[test]
+    fn test_color_scheme_palettes() {
+        let expected = [
+            (ColorScheme::Default, 6),
+            (ColorScheme::ColorblindSafe, 8),
+            (ColorScheme::Grayscale, 5),
+        ];
+
+        for (scheme, count) in expected {
+            let mut distinct = scheme.palette().to_vec();
+            distinct.sort_by_key(|c| (c.0, c.1, c.2));
+            distinct.dedup();
+            assert_eq!(distinct.len(), count, "{}", scheme.name());
+            assert!(distinct.iter().all(|c| *c != WHITE), "{}", scheme.name());
+        }
+
+        assert_eq!(
+            "Colorblind".parse::<ColorScheme>(),
+            Ok(ColorScheme::ColorblindSafe)
+        );
+        assert!("rainbow".parse::<ColorScheme>().is_err());
+    }
+
+    #[test]
+    fn test_matrix_comparison_chart_renders() {
+        let mut results = Vec::new();
+        for method in ["Standard", "Strassen", "Winograd"] {
+            for (size, ms) in [(64, 2), (128, 9), (256, 40)] {
+                let mut result =
+                    sample_result(&format!("Matrix Multiplication ({})", method), size, &[ms]);
+                result.category = Category::Matrix;
+                results.push(result);
+            }
+        }
+
+        let path = std::env::temp_dir().join("dcp_test_matrix_comparison.png");
+        generate_matrix_comparison_chart(
+            &results,
+            &path.to_string_lossy(),
+            &ChartConfig::default(),
+        )
+        .unwrap();
+
+        assert!(fs::metadata(&path).unwrap().len() > 0);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_charts_from_csv_round_trip() {
+        let mut runner = crate::benchmark::BenchmarkRunner::new();
+        runner.set_show_progress(false);
+        let data = crate::data_generator::DataGenerator::generate_random_integers(2000);
+        runner.benchmark_sort("Merge Sort", &data, 2, false);
+        runner.benchmark_sort("Quick Sort", &data, 2, true);
+
+        let csv = std::env::temp_dir().join("dcp_test_round_trip.csv");
+        let chart = std::env::temp_dir().join("dcp_test_round_trip.png");
+        runner.save_results_csv(&csv.to_string_lossy()).unwrap();
+        let loaded = load_results_csv(&csv.to_string_lossy()).unwrap();
+        generate_performance_charts_from_csv(
+            &csv.to_string_lossy(),
+            &chart.to_string_lossy(),
+            &ChartConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(loaded.len(), runner.get_results().len());
+        assert!(loaded.iter().all(|r| r.category == Category::Sorting));
+        assert!(fs::metadata(&chart).unwrap().len() > 0);
+        let _ = fs::remove_file(&csv);
+        let _ = fs::remove_file(&chart);
+    }
+
+    #[test]
+    fn test_output_format_parsing() {
+        assert_eq!("SVG".parse::<OutputFormat>(), Ok(OutputFormat::Svg));
+        let error = "gif".parse::<OutputFormat>().unwrap_err();
+        assert!(error.contains("png, svg, report, csv, html"));
+    }
+
+    #[test]
+    fn test_report_percentiles() {
+        let runs: Vec<u64> = (1..=100).rev().collect();
+        let results = vec![sample_result("Quick Sort (Parallel)", 1000, &runs)];
+
+        let path = std::env::temp_dir().join("dcp_test_percentiles.md");
+        generate_performance_report(&results, &path.to_string_lossy()).unwrap();
+        let report = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert!(report.contains("## Latency Percentiles"));
+        assert!(report.contains(
+            "- Quick Sort (Parallel) (1000 elements): p50 50.00ms, p90 90.00ms, p99 99.00ms"
+        ));
+    }
+
+    #[test]
+    fn test_report_empirical_complexity() {
+        let results = vec![
+            sample_result("Bubble Sort", 1000, &[4]),
+            sample_result("Bubble Sort", 2000, &[16]),
+            sample_result("Bubble Sort", 4000, &[64]),
+            sample_result("Merge Sort", 1000, &[2]),
+        ];
+
+        let path = std::env::temp_dir().join("dcp_test_complexity.md");
+        generate_performance_report(&results, &path.to_string_lossy()).unwrap();
+        let report = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert!(report.contains("- Bubble Sort: time grows as n^2.00 (fitted over 3 results)"));
+        assert!(!report.contains("- Merge Sort: time grows"));
+    }
+
+    #[test]
+    fn test_report_memory_section() {
+        let results = vec![
+            BenchmarkResult {
+                memory_used: Some(2 * 1024 * 1024),
+                ..sample_result("Merge Sort", 1000, &[2])
+            },
+            sample_result("Bubble Sort", 1000, &[4]),
+        ];
+
+        let path = std::env::temp_dir().join("dcp_test_memory.md");
+        generate_performance_report(&results, &path.to_string_lossy()).unwrap();
+        let report = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert!(report.contains("## Memory: Measured vs Estimated"));
+        assert!(
+            report.contains("- Merge Sort (1000 elements): measured 2.000MB, estimated 0.004MB")
+        );
+        // No model for this algorithm, so no made-up estimate
+        assert!(report.contains("- Bubble Sort (1000 elements): measured N/A, estimated N/A"));
+    }
+
+    #[test]
+    fn test_heatmap_chart_renders() {
+        // Quick Sort has no 5000-element result, leaving a blank cell
+        let results = vec![
+            sample_result("Merge Sort", 1000, &[5, 7, 6]),
+            sample_result("Quick Sort", 1000, &[3, 4, 8]),
+            sample_result("Merge Sort", 5000, &[20, 22, 25]),
+            sample_result("Radix Sort", 5000, &[9, 10, 11]),
+        ];
+
+        let path = std::env::temp_dir().join("dcp_test_heatmap.png");
+        {
+            let root = BitMapBackend::new(&path, (800, 600)).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            draw_heatmap_chart(root.clone(), &results, &ChartConfig::default()).unwrap();
+            root.present().unwrap();
+        }
+
+        assert!(fs::metadata(&path).unwrap().len() > 0);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_report_groups_by_category() {
+        let mut hull = sample_result("Convex Hull", 1000, &[2, 3]);
+        hull.category = Category::Geometry;
+        let results = vec![sample_result("Merge Sort", 1000, &[5, 7]), hull];
+
+        let path = std::env::temp_dir().join("dcp_test_categories.md");
+        generate_performance_report(&results, &path.to_string_lossy()).unwrap();
+        let report = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let sorting = report.find("### Sorting").unwrap();
+        let geometry = report.find("### Geometry").unwrap();
+        assert!(sorting < geometry);
+        assert!(report[geometry..].contains("- Convex Hull (1000 elements)"));
+        assert!(!report[sorting..geometry].contains("Convex Hull"));
+    }
+
+    #[test]
+    fn test_html_report_contents() {
+        let results = vec![
+            sample_result("Merge Sort", 1000, &[5, 7, 6]),
+            sample_result("Quick Sort", 1000, &[3, 4, 8]),
+        ];
+
+        let path = std::env::temp_dir().join("dcp_test_report.html");
+        generate_html_report(&results, &path.to_string_lossy(), &ChartConfig::default()).unwrap();
+        let html = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert!(html.contains("<h2>Summary Statistics</h2>"));
+        assert!(html.contains("<h2>Benchmark Results</h2>"));
+        assert!(html.contains("<h2>Charts</h2>"));
+        assert!(html.contains("<table>"));
+        assert!(html.contains("data:image/png;base64,"));
+    }
+
+    #[test]
+    fn test_chart_png_bytes_rendered_in_memory() {
+        let results = vec![sample_result("Merge Sort", 1000, &[5, 7, 6])];
+        let config = ChartConfig::with_size(640, 480);
+
+        let bytes = draw_performance_charts_png_bytes(&results, &config).unwrap();
+        let reader = png::Decoder::new(bytes.as_slice()).read_info().unwrap();
+        assert_eq!(reader.info().width, 640);
+        assert_eq!(reader.info().height, 480);
+    }
+}
@@ -0,0 +1,58 @@
+//! Allocation counts for the closest pair search
+//!
+//! Installing a counting global allocator affects every test in a binary,
+//! so this lives in its own integration test instead of geometry's unit tests
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use divide_conquer_processor::data_generator::DataGenerator;
+use divide_conquer_processor::geometry::closest_pair_divide_conquer;
+
+/// Counts allocations made by the current thread, so the count is unaffected
+/// by the test harness or other tests running on other threads
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+#[test]
+fn test_closest_pair_allocations_do_not_grow_with_input() {
+    // Below the parallel cutoff (4096 points) everything runs on this thread. The old
+    // per-level left_y/right_y/strip vectors made this grow linearly with n;
+    // now only the x-sorted copy and the scratch buffer are allocated
+    for size in [100, 1000, 4000] {
+        let points = DataGenerator::generate_random_points(size);
+
+        let before = allocations();
+        let result = closest_pair_divide_conquer(&points);
+        let allocated = allocations() - before;
+
+        assert!(result.is_some());
+        assert!(
+            allocated <= 2,
+            "{} allocations for {} points",
+            allocated,
+            size
+        );
+    }
+}